@@ -6,15 +6,55 @@ pub static ERROR_NOT_PAUSED: &[u8] = b"Not paused";
 pub static ERROR_NOT_ADMIN: &[u8] = b"Only admin allowed";
 pub static ERROR_NOT_BOT: &[u8] = b"Only bot allowed";
 
+pub static ERROR_INVALID_ADDRESS: &[u8] = b"Invalid address";
+pub static ERROR_INVALID_TOKEN_IDENTIFIER: &[u8] = b"Invalid token identifier";
+pub static ERROR_INVALID_PERCENTAGE: &[u8] = b"Invalid percentage";
+
+pub static ERROR_MIN_AMOUNT_OUT_NOT_MET: &[u8] = b"Swap output below minimum amount out";
+pub static ERROR_INVALID_MIN_AMOUNTS_OUT: &[u8] = b"Invalid min amounts out";
+pub static ERROR_DEADLINE_PASSED: &[u8] = b"Deadline passed";
+pub static ERROR_PRICE_DEVIATION_TOO_HIGH: &[u8] = b"Swap price deviates too much from safe price";
+pub static ERROR_SAFE_PRICE_VIEW_NOT_SET: &[u8] = b"Safe price view address not set";
+
 pub static ERROR_STRATEGY_NOT_SET: &[u8] = b"DCA strategy not set";
 pub static ERROR_STRATEGY_ALREADY_SET: &[u8] = b"DCA strategy already set";
 
 pub static ERROR_INVALID_FREQUENCY: &[u8] = b"Invalid DCA frequency";
+pub static ERROR_INVALID_FREQUENCY_BOUNDS: &[u8] = b"Invalid frequency duration bounds";
+pub static ERROR_LAST_FREQUENCY_PRESET: &[u8] =
+    b"Cannot remove the last frequency preset without frequency duration bounds";
 pub static ERROR_INVALID_AMOUNT_PER_SWAP: &[u8] = b"Invalid amount per swap";
+pub static ERROR_INVALID_DCA_TOKEN: &[u8] = b"Invalid DCA token";
+pub static ERROR_INVALID_TAKE_PROFIT_SELL_PERCENTAGE: &[u8] =
+    b"Invalid take profit sell percentage";
+pub static ERROR_INVALID_TRAILING_TAKE_PROFIT_PERCENTAGE: &[u8] =
+    b"Invalid trailing take profit percentage";
+pub static ERROR_INVALID_START_TIMESTAMP: &[u8] = b"Invalid start timestamp";
+pub static ERROR_START_TIMESTAMP_LOCKED: &[u8] =
+    b"Start timestamp cannot change after the first buy";
+pub static ERROR_INVALID_MAX_CATCH_UP_BUYS: &[u8] = b"Invalid max catch up buys";
+pub static ERROR_INVALID_END_TIMESTAMP: &[u8] = b"Invalid end timestamp";
+pub static ERROR_INVALID_MAX_TOTAL_SPEND: &[u8] = b"Max total spend below amount per swap";
+pub static ERROR_INVALID_STOP_LOSS_PERCENTAGE: &[u8] = b"Invalid stop loss percentage";
+pub static ERROR_INVALID_MAX_SLIPPAGE: &[u8] = b"Max slippage above the allowed cap";
 
 pub static ERROR_INVALID_STRATEGY_TOKEN: &[u8] = b"Invalid strategy token";
-pub static ERROR_INSUFFICIENT_STRATEGY_TOKEN_BALANCE: &[u8] = b"Insufficient strategy token balance";
+pub static ERROR_STRATEGY_ALREADY_PAUSED: &[u8] = b"Strategy already paused";
+pub static ERROR_STRATEGY_NOT_PAUSED: &[u8] = b"Strategy not paused";
+pub static ERROR_INSUFFICIENT_STRATEGY_TOKEN_BALANCE: &[u8] =
+    b"Insufficient strategy token balance";
 
-pub static ERROR_INVALID_USDC_TOKEN: &[u8] = b"Invalid USDC token";
-pub static ERROR_INVALID_USDC_AMOUNT: &[u8] = b"Invalid USDC amount";
-pub static ERROR_INVALID_DCA_TOKEN_AMOUNT: &[u8] = b"Invalid DCA token amount";
\ No newline at end of file
+pub static ERROR_INVALID_QUOTE_TOKEN: &[u8] = b"Invalid quote token";
+pub static ERROR_INVALID_QUOTE_TOKEN_AMOUNT: &[u8] = b"Invalid quote token amount";
+pub static ERROR_INVALID_BUY_SIZING: &[u8] = b"Invalid buy sizing";
+pub static ERROR_INVALID_DIP_MULTIPLIER: &[u8] = b"Invalid dip multiplier";
+pub static ERROR_INVALID_STRATEGY_DIRECTION: &[u8] = b"Invalid strategy direction";
+pub static ERROR_INVALID_COST_BASIS: &[u8] = b"Invalid cost basis";
+pub static ERROR_NOTHING_TO_WITHDRAW: &[u8] = b"Nothing to withdraw";
+pub static ERROR_INVALID_DEPOSIT_PAYMENT: &[u8] = b"Invalid deposit payment";
+pub static ERROR_MIN_AMOUNT_OUT_REQUIRED: &[u8] =
+    b"Minimum amount out required for swapped deposits";
+pub static ERROR_INITIAL_DEPOSIT_TOO_LOW: &[u8] = b"Initial deposit below minimum";
+pub static ERROR_SAME_DCA_AND_QUOTE_TOKEN: &[u8] = b"DCA token and quote token must differ";
+pub static ERROR_INVALID_DCA_TOKEN_AMOUNT: &[u8] = b"Invalid DCA token amount";
+pub static ERROR_INVALID_STRATEGY_TOKEN_ATTRIBUTES: &[u8] = b"Invalid strategy token attributes";
@@ -11,12 +11,16 @@ pub type DcaFrequency<M> = ManagedBuffer<M>;
 pub type DurationInMillis = u64;
 pub type DcaFrequencyInMillis<M> = MultiValue2<DcaFrequency<M>, DurationInMillis>;
 pub type DcaToken<M> = EgldOrEsdtTokenIdentifier<M>;
+pub type QuoteToken<M> = EgldOrEsdtTokenIdentifier<M>;
 pub type AmountPerSwap<M> = BigUint<M>;
 pub type MinAmountPerSwap<M> = BigUint<M>;
+pub type QuoteTokenMinAmountPerSwap<M> = MultiValue2<QuoteToken<M>, MinAmountPerSwap<M>>;
+pub type TokenDecimals = u32;
+pub type DcaTokenDecimals<M> = MultiValue2<DcaToken<M>, TokenDecimals>;
 pub type StrategyToken<M> = TokenIdentifier<M>;
 pub type DcaSetup<M> = MultiValue8<
-    DcaToken<M>,
-    MinAmountPerSwap<M>,
+    ManagedVec<M, DcaToken<M>>,
+    ManagedVec<M, QuoteToken<M>>,
     StrategyToken<M>,
     Percentage,
     Percentage,
@@ -31,23 +35,65 @@ pub type DcaSetup<M> = MultiValue8<
     TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug, ManagedVecItem,
 )]
 pub enum TokenWithdrawn {
-    Usdc = 1,
+    QuoteToken = 1,
     DcaToken = 2,
 }
 
+/// How a buy handles the schedule intervals missed since the strategy was due
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug, ManagedVecItem,
+)]
+pub enum CatchUpPolicy {
+    Skip = 0,
+    CatchUp = 1,
+}
+
+/// What happens to the quote tokens received from a take profit
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug, ManagedVecItem,
+)]
+pub enum PostTakeProfitAction {
+    Reinvest = 0,
+    Pause = 1,
+    Withdraw = 2,
+}
+
+/// How the quote token amount spent by each buy is sized
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug, ManagedVecItem,
+)]
+pub enum BuySizingMode {
+    Fixed = 0,
+    ValueAveraging = 1,
+    PercentageOfBalance = 2,
+}
+
+/// Whether a strategy's scheduled swaps buy the DCA token or sell it back into the quote token
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug, ManagedVecItem,
+)]
+pub enum StrategyDirection {
+    Buy = 0,
+    Sell = 1,
+}
+
 #[type_abi]
 #[derive(
     TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug, ManagedVecItem,
 )]
 pub struct Swap<M: ManagedTypeApi> {
-    pub usdc_amount: Amount<M>,
+    pub quote_token_amount: Amount<M>,
     pub dca_token_amount: Amount<M>,
     pub timestamp_millis: TimestampInMillis,
 }
 impl<M: ManagedTypeApi> Default for Swap<M> {
     fn default() -> Self {
         Self {
-            usdc_amount: BigUint::zero(),
+            quote_token_amount: BigUint::zero(),
             dca_token_amount: BigUint::zero(),
             timestamp_millis: 0,
         }
@@ -55,12 +101,12 @@ impl<M: ManagedTypeApi> Default for Swap<M> {
 }
 impl<M: ManagedTypeApi> Swap<M> {
     pub fn new(
-        usdc_amount: Amount<M>,
+        quote_token_amount: Amount<M>,
         dca_token_amount: Amount<M>,
         timestamp_millis: TimestampInMillis,
     ) -> Self {
         Self {
-            usdc_amount,
+            quote_token_amount,
             dca_token_amount,
             timestamp_millis,
         }
@@ -72,72 +118,222 @@ impl<M: ManagedTypeApi> Swap<M> {
     }
 }
 
+/// Minimum output expected by the bot for the batched swap of a token pair
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug, ManagedVecItem,
+)]
+pub struct SwapMinAmountOut<M: ManagedTypeApi> {
+    pub token_in: EgldOrEsdtTokenIdentifier<M>,
+    pub token_out: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: Amount<M>,
+}
+
+/// User chosen settings of a DCA strategy, passed to `createStrategy` and `modifyStrategy`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug)]
+pub struct StrategyConfig<M: ManagedTypeApi> {
+    /// The amount of quote token to be swapped in each DCA operation.
+    pub amount_per_swap: AmountPerSwap<M>,
+    /// The duration in milliseconds between DCA operations. Must be within the frequency duration bounds;
+    /// a duration matching a named preset (e.g., daily, weekly) is stored with its name.
+    pub frequency_in_millis: DurationInMillis,
+    /// The percentage at which to take profit from DCA operations.
+    pub take_profit_percentage: Percentage,
+    /// The max slippage accepted on the strategy's swaps, up to the admin set cap. Zero uses the contract wide slippage.
+    pub max_slippage_percentage: Percentage,
+    /// The loss below cost basis at which the position is sold. Zero disables the stop loss.
+    pub stop_loss_percentage: Percentage,
+    /// The share of the DCA token balance sold on take profit. Zero sells the whole position.
+    pub take_profit_sell_percentage: Percentage,
+    /// The retrace from the high-water mark, once the take profit target is reached, at which the position is sold.
+    /// Zero sells as soon as the target is reached.
+    pub trailing_take_profit_percentage: Percentage,
+    /// What happens to the take profit proceeds: reinvested, reinvested with the strategy paused,
+    /// or sent to the strategy owner.
+    pub post_take_profit_action: PostTakeProfitAction,
+    /// The timestamp in milliseconds before which the strategy does not swap. Zero to start immediately.
+    /// Swaps are scheduled at this timestamp (or the creation timestamp) plus a multiple of the frequency.
+    pub start_ts_millis: TimestampInMillis,
+    /// Whether a late swap skips the missed intervals or swaps once per missed interval.
+    pub catch_up_policy: CatchUpPolicy,
+    /// The max number of intervals swapped for at once when catching up. Zero with the skip policy.
+    pub max_catch_up_buys: u64,
+    /// The number of buys, or scheduled sells, after which the strategy completes. Zero for no limit.
+    pub max_buys: u64,
+    /// The timestamp in milliseconds after which the strategy completes. Zero for no end date.
+    pub end_ts_millis: TimestampInMillis,
+    /// The total quote token amount the strategy buys with at most. Zero for no budget.
+    pub max_total_spend: Amount<M>,
+    /// Whether each buy spends the amount per swap; with value averaging, the amount bringing the position's
+    /// quote token value up to a target path growing by the amount per swap each interval; or, with percentage
+    /// of balance, a share of the quote token balance at execution time.
+    pub buy_sizing_mode: BuySizingMode,
+    /// The least amount a value averaging or percentage of balance buy spends per interval, above zero in
+    /// those modes. Zero in fixed mode.
+    pub min_amount_per_swap: Amount<M>,
+    /// The most a value averaging buy spends per interval. Zero in the other modes.
+    pub max_amount_per_swap: Amount<M>,
+    /// How far the pool price must be below the average entry price or the last buy's price for a buy
+    /// to be multiplied. Zero disables buying the dip.
+    pub dip_threshold_percentage: Percentage,
+    /// The factor applied to the buy amount when buying the dip (e.g., 20000 for 2x). Zero when disabled.
+    pub dip_multiplier_percentage: Percentage,
+    /// The share of the quote token balance a percentage of balance buy spends per interval. Zero in the other modes.
+    pub balance_percentage_per_swap: Percentage,
+    /// The price above which the strategy does not buy, in quote token units per whole DCA token
+    /// (10^decimals DCA token units). Zero for no price ceiling.
+    pub max_buy_price: Amount<M>,
+    /// Whether the scheduled swaps buy the DCA token or sell it into the quote token (DCA out).
+    /// Selling requires fixed sizing, and no dip multiplier, max buy price or max total spend.
+    pub direction: StrategyDirection,
+    /// The DCA token amount sold per interval when selling. Zero sells the quote token equivalent of the
+    /// amount per swap at the pool price. Zero when buying.
+    pub dca_token_amount_per_swap: Amount<M>,
+}
+impl<M: ManagedTypeApi> Default for StrategyConfig<M> {
+    fn default() -> Self {
+        Self {
+            amount_per_swap: BigUint::zero(),
+            frequency_in_millis: 0,
+            take_profit_percentage: 0,
+            max_slippage_percentage: 0,
+            stop_loss_percentage: 0,
+            take_profit_sell_percentage: 0,
+            trailing_take_profit_percentage: 0,
+            post_take_profit_action: PostTakeProfitAction::Reinvest,
+            start_ts_millis: 0,
+            catch_up_policy: CatchUpPolicy::Skip,
+            max_catch_up_buys: 0,
+            max_buys: 0,
+            end_ts_millis: 0,
+            max_total_spend: BigUint::zero(),
+            buy_sizing_mode: BuySizingMode::Fixed,
+            min_amount_per_swap: BigUint::zero(),
+            max_amount_per_swap: BigUint::zero(),
+            dip_threshold_percentage: 0,
+            dip_multiplier_percentage: 0,
+            balance_percentage_per_swap: 0,
+            max_buy_price: BigUint::zero(),
+            direction: StrategyDirection::Buy,
+            dca_token_amount_per_swap: BigUint::zero(),
+        }
+    }
+}
+
 #[type_abi]
 #[derive(
     TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug, ManagedVecItem,
 )]
 pub struct StrategyTokenAttributes<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub dca_token: DcaToken<M>,
+    pub quote_token: QuoteToken<M>,
     pub amount_per_swap: AmountPerSwap<M>,
     pub dca_frequency: DcaFrequency<M>,
     pub frequency_in_millis: DurationInMillis,
     pub take_profit_percentage: Percentage,
-    pub usdc_balance: Amount<M>,
+    pub take_profit_sell_percentage: Percentage,
+    pub trailing_take_profit_percentage: Percentage,
+    pub max_slippage_percentage: Percentage,
+    pub stop_loss_percentage: Percentage,
+    pub post_take_profit_action: PostTakeProfitAction,
+    pub start_ts_millis: TimestampInMillis,
+    pub catch_up_policy: CatchUpPolicy,
+    pub max_catch_up_buys: u64,
+    pub max_buys: u64,
+    pub end_ts_millis: TimestampInMillis,
+    pub max_total_spend: Amount<M>,
+    pub buy_sizing_mode: BuySizingMode,
+    pub min_amount_per_swap: Amount<M>,
+    pub max_amount_per_swap: Amount<M>,
+    pub dip_threshold_percentage: Percentage,
+    pub dip_multiplier_percentage: Percentage,
+    pub balance_percentage_per_swap: Percentage,
+    pub max_buy_price: Amount<M>,
+    pub direction: StrategyDirection,
+    pub dca_token_amount_per_swap: Amount<M>,
+    pub quote_token_balance: Amount<M>,
     pub dca_token_balance: Amount<M>,
+    pub take_profit_high_water_mark: Amount<M>,
     pub last_executed_ts_millis: TimestampInMillis,
+    pub next_buy_ts_millis: TimestampInMillis,
+    pub is_paused: bool,
+    pub is_completed: bool,
+    pub cost_basis: Amount<M>,
+    pub fee_cost_basis: Amount<M>,
+    pub last_sell: Swap<M>,
+    pub scheduled_buys: u64,
+    pub total_spent: Amount<M>,
+    pub scheduled_sells: u64,
     pub buys: ManagedVec<M, Swap<M>>,
     pub sells: ManagedVec<M, Swap<M>>,
 }
 impl<M: ManagedTypeApi> Default for StrategyTokenAttributes<M> {
     fn default() -> Self {
         Self {
+            owner: ManagedAddress::zero(),
+            dca_token: EgldOrEsdtTokenIdentifier::egld(),
+            quote_token: EgldOrEsdtTokenIdentifier::egld(),
             amount_per_swap: BigUint::zero(),
             dca_frequency: ManagedBuffer::new(),
             frequency_in_millis: 0,
             take_profit_percentage: 0,
-            usdc_balance: BigUint::zero(),
+            take_profit_sell_percentage: 0,
+            trailing_take_profit_percentage: 0,
+            max_slippage_percentage: 0,
+            stop_loss_percentage: 0,
+            post_take_profit_action: PostTakeProfitAction::Reinvest,
+            start_ts_millis: 0,
+            catch_up_policy: CatchUpPolicy::Skip,
+            max_catch_up_buys: 0,
+            max_buys: 0,
+            end_ts_millis: 0,
+            max_total_spend: BigUint::zero(),
+            buy_sizing_mode: BuySizingMode::Fixed,
+            min_amount_per_swap: BigUint::zero(),
+            max_amount_per_swap: BigUint::zero(),
+            dip_threshold_percentage: 0,
+            dip_multiplier_percentage: 0,
+            balance_percentage_per_swap: 0,
+            max_buy_price: BigUint::zero(),
+            direction: StrategyDirection::Buy,
+            dca_token_amount_per_swap: BigUint::zero(),
+            quote_token_balance: BigUint::zero(),
             dca_token_balance: BigUint::zero(),
+            take_profit_high_water_mark: BigUint::zero(),
             last_executed_ts_millis: 0,
+            next_buy_ts_millis: 0,
+            is_paused: false,
+            is_completed: false,
+            cost_basis: BigUint::zero(),
+            fee_cost_basis: BigUint::zero(),
+            last_sell: Swap::default(),
+            scheduled_buys: 0,
+            total_spent: BigUint::zero(),
+            scheduled_sells: 0,
             buys: ManagedVec::new(),
             sells: ManagedVec::new(),
         }
     }
 }
-impl<M: ManagedTypeApi> StrategyTokenAttributes<M> {
-    pub fn new(
-        amount_per_swap: AmountPerSwap<M>,
-        dca_frequency: DcaFrequency<M>,
-        frequency_in_millis: DurationInMillis,
-        take_profit_percentage: Percentage,
-        usdc_balance: Amount<M>,
-        dca_token_balance: Amount<M>,
-        last_executed_ts_millis: TimestampInMillis,
-        buys: ManagedVec<M, Swap<M>>,
-        sells: ManagedVec<M, Swap<M>>,
-    ) -> Self {
-        Self {
-            amount_per_swap,
-            dca_frequency,
-            frequency_in_millis,
-            take_profit_percentage,
-            usdc_balance,
-            dca_token_balance,
-            last_executed_ts_millis,
-            buys,
-            sells,
-        }
-    }
+
+/// Strategy token attributes of the single DCA token contract version, encoded without a version byte.
+/// They are converted to `StrategyTokenAttributes` when read, and stored in the current format when next updated.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Eq, Debug)]
+pub struct LegacyStrategyTokenAttributes<M: ManagedTypeApi> {
+    pub amount_per_swap: AmountPerSwap<M>,
+    pub dca_frequency: DcaFrequency<M>,
+    pub frequency_in_millis: DurationInMillis,
+    pub take_profit_percentage: Percentage,
+    pub usdc_balance: Amount<M>,
+    pub dca_token_balance: Amount<M>,
+    pub last_executed_ts_millis: TimestampInMillis,
+    pub buys: ManagedVec<M, Swap<M>>,
+    pub sells: ManagedVec<M, Swap<M>>,
 }
 
-pub type StrategyTokenAttributesAsType<M> = MultiValue11<
-    Nonce,
-    AmountPerSwap<M>,
-    DcaFrequency<M>,
-    DurationInMillis,
-    Percentage,
-    Amount<M>,
-    Amount<M>,
-    TimestampInMillis,
-    bool,
-    ManagedVec<M, Swap<M>>,
-    ManagedVec<M, Swap<M>>,
->;
+/// Strategy nonce, attributes, whether the take profit condition is met, whether the
+/// stop loss condition is met and whether the DCA token price is within the buy price ceiling
+pub type StrategyTokenAttributesAsType<M> =
+    MultiValue5<Nonce, StrategyTokenAttributes<M>, bool, bool, bool>;
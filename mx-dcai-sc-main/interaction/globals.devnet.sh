@@ -42,6 +42,12 @@ fi
 
 OWNER_ADDRESS_HEX=$(mxpy wallet bech32 --decode $OWNER_ADDRESS)
 
+# === XEXCHANGE ===
+
+XEXCHANGE_ROUTER_SC_ADDRESS="erd1qqqqqqqqqqqqqpgqa7hv0nahgsl8tz0psat46x0tchm0wuyc0n4s6q28ad"
+WRAPPER_SC_ADDRESS="erd1qqqqqqqqqqqqqpgqpv09kfzry5y4sj05udcngesat07umyj70n4sa2c0rp"
+SAFE_PRICE_VIEW_SC_ADDRESS="" # xExchange safe price view contract, required by deploy and upgrade
+
 # === TOKENS ===
 
 EGLD="EGLD"
@@ -42,6 +42,12 @@ fi
 
 OWNER_ADDRESS_HEX=$(mxpy wallet bech32 --decode $OWNER_ADDRESS)
 
+# === XEXCHANGE ===
+
+XEXCHANGE_ROUTER_SC_ADDRESS="erd1qqqqqqqqqqqqqpgqq66xk9gfr4esuhem3jru86wg5hvp33a62jps2fy57p"
+WRAPPER_SC_ADDRESS="erd1qqqqqqqqqqqqqpgqhe8t5jewej70zupmh44jurgn29psua5l2jps3ntjj3"
+SAFE_PRICE_VIEW_SC_ADDRESS="" # xExchange safe price view contract, required by deploy and upgrade
+
 # === TOKENS ===
 
 EGLD="EGLD"
@@ -54,40 +54,125 @@ source snippets.sh
 # Example: deploy "$(./encode.sh arg1)@$(./encode.sh arg2)"
 
 setup() {        
-    runTx '' 50000000000000000 setup @$(./encode.sh $1)@$(./encode.sh $2)@$(./encode.sh $3)@$(./encode.sh $4)@$(./encode.sh $5)@$(./encode.sh Daily)@$(./encode.sh 86400000)@$(./encode.sh Weekly)@$(./encode.sh 604800000)@$(./encode.sh Monthly)@$(./encode.sh 2592000000) 70000000
+    runTx '' 50000000000000000 setup @$(./encode.sh $1)@$(./encode.sh $2)@$(./encode.sh $3)@$(./encode.sh $4)@$(./encode.sh Daily)@$(./encode.sh 86400000)@$(./encode.sh Weekly)@$(./encode.sh 604800000)@$(./encode.sh Monthly)@$(./encode.sh 2592000000) 70000000
+}
+
+addAllowedDcaTokens() {
+    local ARGS=""
+    while [ $# -ge 2 ]; do
+        ARGS="$ARGS@$(./encode.sh $1)@$(./encode.sh $2)"
+        shift 2
+    done
+    runTx '' '' addAllowedDcaTokens $ARGS 8000000
+}
+
+addAllowedQuoteTokens() {
+    runTx '' '' addAllowedQuoteTokens @$(./encode.sh $1)@$(./encode.sh $2) 8000000
+}
+
+# Nested encodes the 23 StrategyConfig fields, in declaration order, into a single argument
+encodeStrategyConfig() {
+    python3 - "$@" << 'EOF'
+import sys
+
+# b: BigUint, u: u64, e: fieldless enum
+TYPES = "buuuuuueueuuubebbuuubeb"
+
+values = sys.argv[1:]
+if len(values) != len(TYPES):
+    sys.exit(f"Expected {len(TYPES)} StrategyConfig fields, got {len(values)}")
+
+encoded = ""
+for kind, value in zip(TYPES, values):
+    value = int(value)
+    if kind == "b":
+        raw = value.to_bytes((value.bit_length() + 7) // 8, "big").hex()
+        encoded += f"{len(raw) // 2:08x}{raw}"
+    elif kind == "u":
+        encoded += f"{value:016x}"
+    else:
+        encoded += f"{value:02x}"
+
+print(encoded)
+EOF
 }
 
 createStrategy() {
-    runTx '' '' createStrategy @$(./encode.sh $1)@$(./encode.sh $2)@$(./encode.sh $3) 7000000
+    runTx '' '' createStrategy @$(./encode.sh $1)@$(./encode.sh $2)@$(encodeStrategyConfig "${@:3:23}") 7000000
+}
+
+createFundedStrategy() {
+    runTx '' '' ESDTTransfer @$(./encode.sh $2)@$(./encode.sh ${26})@$(./encode.sh createStrategy)@$(./encode.sh $1)@$(./encode.sh $2)@$(encodeStrategyConfig "${@:3:23}") 8000000
 }
 
 modifyStrategy() {
-    runTx $OWNER_ADDRESS '' ESDTNFTTransfer @$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $SC_ADDRESS)@$(./encode.sh modifyStrategy)@$(./encode.sh $3)@$(./encode.sh $4)@$(./encode.sh $5) 8000000
+    runTx $OWNER_ADDRESS '' ESDTNFTTransfer @$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $SC_ADDRESS)@$(./encode.sh modifyStrategy)@$(encodeStrategyConfig "${@:3:23}") 8000000
 }
 
 deleteStrategy() {
     runTx $OWNER_ADDRESS '' ESDTNFTTransfer @$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $SC_ADDRESS)@$(./encode.sh deleteStrategy) 6000000
 }
 
+pauseStrategy() {
+    runTx $OWNER_ADDRESS '' ESDTNFTTransfer @$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $SC_ADDRESS)@$(./encode.sh pauseStrategy) 6000000
+}
+
+resumeStrategy() {
+    runTx $OWNER_ADDRESS '' ESDTNFTTransfer @$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $SC_ADDRESS)@$(./encode.sh resumeStrategy) 6000000
+}
+
 deposit() {
-    runTx $OWNER_ADDRESS '' MultiESDTNFTTransfer @$(./encode.sh $SC_ADDRESS)@02@$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $USDC)@00@$(./encode.sh $3)@$(./encode.sh deposit) 8000000
+    runTx $OWNER_ADDRESS '' MultiESDTNFTTransfer @$(./encode.sh $SC_ADDRESS)@02@$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $3)@00@$(./encode.sh $4)@$(./encode.sh deposit) 8000000
+}
+
+depositFor() {
+    runTx '' '' ESDTTransfer @$(./encode.sh $2)@$(./encode.sh $3)@$(./encode.sh depositFor)@$(./encode.sh $1) 8000000
+}
+
+importDcaToken() {
+    runTx $OWNER_ADDRESS '' MultiESDTNFTTransfer @$(./encode.sh $SC_ADDRESS)@02@$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $3)@00@$(./encode.sh $4)@$(./encode.sh importDcaToken)@$(./encode.sh $5) 30000000
+}
+
+zapDeposit() {
+    runTx $OWNER_ADDRESS '' MultiESDTNFTTransfer @$(./encode.sh $SC_ADDRESS)@02@$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $3)@00@$(./encode.sh $4)@$(./encode.sh deposit)@$(./encode.sh $5) 30000000
 }
 
 withdraw() {
     runTx $OWNER_ADDRESS '' ESDTNFTTransfer @$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $SC_ADDRESS)@$(./encode.sh withdraw)@$(./encode.sh $3)@$(./encode.sh $4) 8000000
 }
 
+withdrawTo() {
+    runTx $OWNER_ADDRESS '' ESDTNFTTransfer @$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $SC_ADDRESS)@$(./encode.sh withdraw)@$(./encode.sh $3)@$(./encode.sh $4)@$(./encode.sh $5) 8000000
+}
+
+withdrawAll() {
+    runTx $OWNER_ADDRESS '' ESDTNFTTransfer @$(./encode.sh $1)@$(./encode.sh $2)@01@$(./encode.sh $SC_ADDRESS)@$(./encode.sh withdrawAll) 8000000
+}
+
 ######################## START ########################
 
-# deploy '' 110000000
-# upgrade '' 110000000
+# deploy "$XEXCHANGE_ROUTER_SC_ADDRESS $WRAPPER_SC_ADDRESS $SAFE_PRICE_VIEW_SC_ADDRESS str:$USDC str:$WEGLD" 110000000
+# upgrade "$XEXCHANGE_ROUTER_SC_ADDRESS $WRAPPER_SC_ADDRESS $SAFE_PRICE_VIEW_SC_ADDRESS str:$USDC str:$WEGLD" 110000000
+# Upgrading the single DCA token version also passes the DCA token decimals:
+# upgrade "$XEXCHANGE_ROUTER_SC_ADDRESS $WRAPPER_SC_ADDRESS $SAFE_PRICE_VIEW_SC_ADDRESS str:$USDC str:$WEGLD 18" 110000000
 
+# runTx '' '' setSafePriceViewScAddress @$(./encode.sh $SAFE_PRICE_VIEW_SC_ADDRESS) 5000000
 # runTx '' '' setBotAddress @$(./encode.sh erd1s5ufsgtmzwtp6wrlwtmaqzs24t0p9evmp58p33xmukxwetl8u76sa2p9rv) 5000000
 # runTx '' '' addAdmins @$(./encode.sh erd1u5p4njlv9rxvzvmhsxjypa69t2dran33x9ttpx0ghft7tt35wpfsxgynw4) 8000000
 
-# setup $EGLD EGLD eGold 500000 2000
-# createStrategy 1000000 Weekly 1000
-# modifyStrategy DCAIEGLD-37d10f 01 1000000 Daily 10
+# setup EGLD eGold 500000 2000
+# addAllowedDcaTokens $EGLD 18 $MEX 18 $XOXNO 18
+# addAllowedQuoteTokens $WEGLD 10000000000000000
+# createStrategy $EGLD $USDC 1000000 604800000 1000 100 2000 5000 500 2 0 1 3 12 0 0 0 0 0 0 0 0 0 0 0
+# createFundedStrategy $EGLD $USDC 1000000 604800000 1000 100 2000 5000 500 2 0 1 3 12 0 0 1 500000 2000000 1000 20000 0 40000000 0 0 50000000
+# modifyStrategy DCAIEGLD-37d10f 01 1000000 86400000 10 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 1 0
 # deleteStrategy DCAIEGLD-37d10f 01
-# deposit DCAIEGLD-37d10f 3 5000000
-# withdraw DCAIEGLD-37d10f 3 2000000 01
\ No newline at end of file
+# pauseStrategy DCAIEGLD-37d10f 01
+# resumeStrategy DCAIEGLD-37d10f 01
+# deposit DCAIEGLD-37d10f 3 $USDC 5000000
+# depositFor 3 $USDC 5000000
+# importDcaToken DCAIEGLD-37d10f 3 EGLD-000000 1000000000000000000 30000000
+# zapDeposit DCAIEGLD-37d10f 3 EGLD-000000 100000000000000000 2000000
+# withdraw DCAIEGLD-37d10f 3 2000000 01
+# withdrawTo DCAIEGLD-37d10f 3 2000000 01 $OWNER_ADDRESS
+# withdrawAll DCAIEGLD-37d10f 3
\ No newline at end of file
@@ -1,7 +1,13 @@
 use constants::MAX_PERCENTAGE;
-use errors::ERROR_NOT_BOT;
+use errors::{
+    ERROR_DEADLINE_PASSED, ERROR_INVALID_MIN_AMOUNTS_OUT, ERROR_MIN_AMOUNT_OUT_NOT_MET,
+    ERROR_NOT_BOT,
+};
 use multiversx_sc::imports::*;
-use structs::{StrategyTokenAttributes, Swap};
+use structs::{
+    PostTakeProfitAction, StrategyDirection, StrategyTokenAttributes, Swap, SwapMinAmountOut,
+    TimestampInMillis,
+};
 
 // pub type TestOutupt<M> = MultiValue4<BigUint<M>, BigUint<M>, bool, u64>;
 /// Smart Contract module that offers bot management capabilities.
@@ -17,6 +23,7 @@ pub trait BotModule:
     + crate::admins::AdminsModule
     + crate::xexchange::wrapper_module::WrapperModule
     + crate::xexchange::pair_module::XExchangePairModule
+    + crate::xexchange::safe_price_module::SafePriceModule
     + crate::xexchange::router_module::XExchangeRouterModule
     + crate::strategy::StrategyModule
 {
@@ -38,16 +45,252 @@ pub trait BotModule:
     /// Endpoint executed by the bot to perform buy operations for multiple DCA strategies.
     ///
     /// Parameters:
+    /// - `deadline_ts_millis`: The timestamp after which the call reverts, or 0 for no deadline.
+    /// - `min_amounts_out`: The minimum DCA token amounts, or quote token amounts for selling strategies, the bot
+    ///   expects per token pair, summed across the pair's slippage batches. Pairs without an entry are only bound
+    ///   by the slippage and safe price checks. Each entry must be for a distinct token pair the call swaps.
     /// - `nonces`: A list of nonces representing the DCA strategies to execute.
     ///
-    /// The bot checks each strategy to see if it is eligible for execution based on the USDC balance,
-    /// the last executed timestamp, and the defined frequency. If eligible, it aggregates the total amount to swap,
-    /// performs the swap, and updates each strategy's attributes accordingly.
+    /// The bot checks each strategy to see if it is eligible for execution based on the quote token balance,
+    /// and the next scheduled buy timestamp, which is anchored to the start timestamp. Paused and completed strategies are skipped,
+    /// as are strategies whose max buy price is below the current DCA token price, which stay due for the next call.
+    /// Selling strategies (DCA out) are eligible based on their DCA token balance instead.
+    /// A strategy that reaches one of its end conditions (max buys, end timestamp or max total spend)
+    /// is marked as completed and emits `strategyCompleted`. Eligible strategies are grouped by
+    /// their direction, DCA token, quote token and slippage; for each group it aggregates the total amount to swap,
+    /// performs a single swap, and updates each strategy's attributes accordingly.
     ///
     /// It will skip strategies that do not meet the criteria and will return any
-    /// dust amount to the caller. It will fail only if no strategies at all are eligible for execution.
+    /// dust amount to the caller. It will fail only if no strategies at all are eligible for execution
+    /// and none was completed.
     #[endpoint(buy)]
-    fn buy(&self, nonces: MultiValueEncoded<u64>) {
+    fn buy(
+        &self,
+        deadline_ts_millis: TimestampInMillis,
+        min_amounts_out: ManagedVec<SwapMinAmountOut<Self::Api>>,
+        nonces: MultiValueEncoded<u64>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_bot(&caller);
+
+        let ts_millis = self
+            .blockchain()
+            .get_block_timestamp_millis()
+            .as_u64_millis();
+        self.require_deadline_not_passed(deadline_ts_millis, ts_millis);
+
+        let mut all_nonces: ManagedVec<u64> = ManagedVec::new();
+        let mut all_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>> = ManagedVec::new();
+        let mut strategy_completed = false;
+        for nonce in nonces.into_iter() {
+            let mut attributes = self.get_strategy_token_attributes(nonce);
+            if attributes.is_completed
+                || attributes.is_paused
+                || &attributes.amount_per_swap * attributes.frequency_in_millis == 0
+            {
+                continue;
+            }
+
+            if self.is_strategy_completed(&attributes, ts_millis) {
+                attributes.is_completed = true;
+                self.strategy_token()
+                    .nft_update_attributes(nonce, &self.attributes_to_buffer(&attributes));
+                self.event_strategy_completed(nonce);
+                strategy_completed = true;
+                continue;
+            }
+
+            let is_eligible = match attributes.direction {
+                StrategyDirection::Buy => {
+                    attributes.quote_token_balance >= self.get_min_buy_amount(&attributes)
+                        && self.is_within_max_buy_price(&attributes)
+                }
+                StrategyDirection::Sell => self.get_scheduled_sell_amount(&attributes, 1) > 0,
+            };
+            if is_eligible && ts_millis >= attributes.next_buy_ts_millis {
+                all_attributes.push(attributes);
+                all_nonces.push(nonce);
+            }
+        }
+
+        require!(
+            !all_nonces.is_empty() || strategy_completed,
+            "No valid strategies to execute"
+        );
+
+        let mut amounts_out: ManagedVec<SwapMinAmountOut<Self::Api>> = ManagedVec::new();
+        while !all_nonces.is_empty() {
+            let (batch_nonces, batch_attributes) =
+                self.take_next_swap_batch(&mut all_nonces, &mut all_attributes);
+            let first_attributes = batch_attributes.get(0).clone();
+            match first_attributes.direction {
+                StrategyDirection::Buy => {
+                    let amount_out =
+                        self.execute_buy_batch(&caller, ts_millis, batch_nonces, batch_attributes);
+                    self.add_amount_out(
+                        &mut amounts_out,
+                        &first_attributes.quote_token,
+                        &first_attributes.dca_token,
+                        &amount_out,
+                    );
+                }
+                StrategyDirection::Sell => {
+                    let amount_out = self.execute_scheduled_sell_batch(
+                        &caller,
+                        ts_millis,
+                        batch_nonces,
+                        batch_attributes,
+                    );
+                    self.add_amount_out(
+                        &mut amounts_out,
+                        &first_attributes.dca_token,
+                        &first_attributes.quote_token,
+                        &amount_out,
+                    );
+                }
+            }
+        }
+        self.require_min_amounts_out(&min_amounts_out, &amounts_out);
+    }
+
+    /// Endpoint executed by the bot to perform take profit operations for multiple DCA strategies.
+    ///
+    /// Parameters:
+    /// - `deadline_ts_millis`: The timestamp after which the call reverts, or 0 for no deadline.
+    /// - `min_amounts_out`: The minimum quote token amounts the bot expects per token pair, summed across
+    ///   the pair's slippage batches, before profit fees. Pairs without an entry are only bound by the slippage
+    ///   and safe price checks. Each entry must be for a distinct token pair the call swaps.
+    /// - `nonces`: A list of nonces representing the DCA strategies to evaluate for take profit.
+    ///
+    /// The bot checks each strategy to see if the take profit condition is met based on the
+    /// DCA token balance and the defined take profit percentage, skipping paused strategies. For strategies in trailing mode, the
+    /// call records the highest pool price of the DCA token once the condition is met, and the
+    /// strategy only becomes eligible when the price retraces from that high-water mark by the trailing
+    /// take profit percentage. Tracking the price rather than the position's value keeps buys made in the
+    /// meantime from hiding a retrace. Eligible strategies are grouped by
+    /// their DCA token, quote token and slippage; for each group it aggregates the take profit sell
+    /// percentage of each DCA token balance, performs a single swap,
+    /// deducts any applicable profit fees, and updates each strategy's attributes accordingly. The quote
+    /// tokens received are reinvested, reinvested with the strategy paused, or sent to the strategy
+    /// owner, depending on the strategy's post take profit action.
+    ///
+    /// It will skip strategies that do not meet the take profit condition and will return any
+    /// dust amount to the caller. It will fail only if no strategies at all are eligible for take profit
+    /// and no high-water mark was updated.
+    #[endpoint(takeProfit)]
+    fn take_profit(
+        &self,
+        deadline_ts_millis: TimestampInMillis,
+        min_amounts_out: ManagedVec<SwapMinAmountOut<Self::Api>>,
+        nonces: MultiValueEncoded<u64>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_is_bot(&caller);
+
+        let ts_millis = self
+            .blockchain()
+            .get_block_timestamp_millis()
+            .as_u64_millis();
+        self.require_deadline_not_passed(deadline_ts_millis, ts_millis);
+
+        let mut all_nonces: ManagedVec<u64> = ManagedVec::new();
+        let mut all_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>> = ManagedVec::new();
+        let mut high_water_mark_updated = false;
+        for nonce in nonces.into_iter() {
+            let mut attributes = self.get_strategy_token_attributes(nonce);
+            if attributes.is_paused
+                || self.get_take_profit_sell_amount(&attributes) == 0
+                || &attributes.amount_per_swap * attributes.frequency_in_millis == 0
+            {
+                continue;
+            }
+
+            let dca_token_quote_equivalent = self.get_dca_token_quote_equivalent(
+                &attributes.dca_token,
+                &attributes.quote_token,
+                &attributes.dca_token_balance,
+            );
+            let is_in_profit = self.is_in_profit(
+                &dca_token_quote_equivalent,
+                attributes.take_profit_percentage,
+                &self.get_take_profit_cost_basis(&attributes),
+            );
+
+            if attributes.trailing_take_profit_percentage == 0 {
+                if is_in_profit {
+                    all_attributes.push(attributes);
+                    all_nonces.push(nonce);
+                }
+                continue;
+            }
+
+            let dca_token_price = self.get_dca_token_price(&attributes);
+            if self.is_trailing_take_profit_retraced(
+                &dca_token_price,
+                attributes.trailing_take_profit_percentage,
+                &attributes.take_profit_high_water_mark,
+            ) {
+                all_attributes.push(attributes);
+                all_nonces.push(nonce);
+            } else if (is_in_profit || attributes.take_profit_high_water_mark > 0)
+                && dca_token_price > attributes.take_profit_high_water_mark
+            {
+                attributes.take_profit_high_water_mark = dca_token_price;
+                self.strategy_token()
+                    .nft_update_attributes(nonce, &self.attributes_to_buffer(&attributes));
+                self.event_take_profit_high_water_mark_updated(
+                    nonce,
+                    &attributes.take_profit_high_water_mark,
+                );
+                high_water_mark_updated = true;
+            }
+        }
+
+        require!(
+            !all_nonces.is_empty() || high_water_mark_updated,
+            "No valid strategies to execute"
+        );
+
+        let mut amounts_out: ManagedVec<SwapMinAmountOut<Self::Api>> = ManagedVec::new();
+        while !all_nonces.is_empty() {
+            let (batch_nonces, batch_attributes) =
+                self.take_next_swap_batch(&mut all_nonces, &mut all_attributes);
+            let first_attributes = batch_attributes.get(0).clone();
+            let amount_out =
+                self.execute_sell_batch(&caller, ts_millis, batch_nonces, batch_attributes, false);
+            self.add_amount_out(
+                &mut amounts_out,
+                &first_attributes.dca_token,
+                &first_attributes.quote_token,
+                &amount_out,
+            );
+        }
+        self.require_min_amounts_out(&min_amounts_out, &amounts_out);
+    }
+
+    /// Endpoint executed by the bot to perform stop loss operations for multiple DCA strategies.
+    ///
+    /// Parameters:
+    /// - `deadline_ts_millis`: The timestamp after which the call reverts, or 0 for no deadline.
+    /// - `min_amounts_out`: The minimum quote token amounts the bot expects per token pair, summed across
+    ///   the pair's slippage batches. Pairs without an entry are only bound by the slippage and safe price checks.
+    ///   Each entry must be for a distinct token pair the call swaps.
+    /// - `nonces`: A list of nonces representing the DCA strategies to evaluate for stop loss.
+    ///
+    /// The bot checks each strategy to see if the value of its DCA token balance dropped below the
+    /// cost basis by the defined stop loss percentage. Eligible strategies are grouped by
+    /// their DCA token, quote token and slippage; for each group it sells the whole DCA token balance
+    /// in a single swap and credits the quote tokens received to each strategy. No profit fee is taken.
+    ///
+    /// It will skip strategies that do not meet the stop loss condition and will return any
+    /// dust amount to the caller. It will fail only if no strategies at all are eligible for stop loss.
+    #[endpoint(stopLoss)]
+    fn stop_loss(
+        &self,
+        deadline_ts_millis: TimestampInMillis,
+        min_amounts_out: ManagedVec<SwapMinAmountOut<Self::Api>>,
+        nonces: MultiValueEncoded<u64>,
+    ) {
         let caller = self.blockchain().get_caller();
         self.require_is_bot(&caller);
 
@@ -55,18 +298,29 @@ pub trait BotModule:
             .blockchain()
             .get_block_timestamp_millis()
             .as_u64_millis();
+        self.require_deadline_not_passed(deadline_ts_millis, ts_millis);
 
-        let mut amount_to_swap: BigUint = BigUint::zero();
         let mut all_nonces: ManagedVec<u64> = ManagedVec::new();
         let mut all_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>> = ManagedVec::new();
         for nonce in nonces.into_iter() {
             let attributes = self.get_strategy_token_attributes(nonce);
+            if attributes.stop_loss_percentage == 0 || attributes.dca_token_balance == 0 {
+                continue;
+            }
 
-            if attributes.usdc_balance >= attributes.amount_per_swap
-                && ts_millis >= attributes.last_executed_ts_millis + attributes.frequency_in_millis
-                && &attributes.amount_per_swap * attributes.frequency_in_millis > 0
+            let dca_token_quote_equivalent = self.get_dca_token_quote_equivalent(
+                &attributes.dca_token,
+                &attributes.quote_token,
+                &attributes.dca_token_balance,
+            );
+
+            if &attributes.amount_per_swap * attributes.frequency_in_millis > 0
+                && self.is_in_loss(
+                    &dca_token_quote_equivalent,
+                    attributes.stop_loss_percentage,
+                    &attributes.cost_basis,
+                )
             {
-                amount_to_swap += &attributes.amount_per_swap;
                 all_attributes.push(attributes);
                 all_nonces.push(nonce);
             }
@@ -74,24 +328,156 @@ pub trait BotModule:
 
         require!(!all_nonces.is_empty(), "No valid strategies to execute");
 
-        let token_out = self.get_dca_token_as_esdt();
+        let mut amounts_out: ManagedVec<SwapMinAmountOut<Self::Api>> = ManagedVec::new();
+        while !all_nonces.is_empty() {
+            let (batch_nonces, batch_attributes) =
+                self.take_next_swap_batch(&mut all_nonces, &mut all_attributes);
+            let first_attributes = batch_attributes.get(0).clone();
+            let amount_out =
+                self.execute_sell_batch(&caller, ts_millis, batch_nonces, batch_attributes, true);
+            self.add_amount_out(
+                &mut amounts_out,
+                &first_attributes.dca_token,
+                &first_attributes.quote_token,
+                &amount_out,
+            );
+        }
+        self.require_min_amounts_out(&min_amounts_out, &amounts_out);
+    }
+
+    // === Views ===
+
+    /// Returns true if the given address can execute bot operations.
+    ///
+    /// This includes the bot address and any admin addresses (as a fallback mechanism).
+    #[view(isBot)]
+    fn is_bot(&self, address: &ManagedAddress) -> bool {
+        &self.bot_address().get() == address || self.admins().contains(address)
+    }
+
+    // #[view(getStrategyTokenTakeProfitInfo)]
+    // fn get_strategy_token_take_profit_info(&self, nonce: u64) -> TestOutupt<Self::Api> {
+    //     let attributes = self.get_strategy_token_attributes(nonce);
+    //     let dca_token_equivalent = self.get_dca_token_quote_equivalent(
+    //         &attributes.dca_token,
+    //         &attributes.quote_token,
+    //         &attributes.dca_token_balance,
+    //     );
+
+    //     let is_met = self.is_take_profit_condition_met(
+    //         &dca_token_equivalent,
+    //         attributes.take_profit_percentage,
+    //         &attributes.buys,
+    //         &attributes.sells,
+    //     );
+
+    //     TestOutupt::from((
+    //         attributes.dca_token_balance,
+    //         dca_token_equivalent,
+    //         is_met,
+    //         attributes.take_profit_percentage,
+    //     ))
+    // }
+
+    // === Private ===
+
+    /// Removes from the given lists the strategies that can share a single swap with the first one
+    /// and returns them as a batch. Strategies share a swap when they have the same direction, DCA token and
+    /// quote token, and the same slippage percentage.
+    fn take_next_swap_batch(
+        &self,
+        nonces: &mut ManagedVec<u64>,
+        attributes: &mut ManagedVec<StrategyTokenAttributes<Self::Api>>,
+    ) -> (
+        ManagedVec<u64>,
+        ManagedVec<StrategyTokenAttributes<Self::Api>>,
+    ) {
+        let first_attributes = attributes.get(0).clone();
+        let first_slippage_percentage =
+            self.get_strategy_slippage_percentage(first_attributes.max_slippage_percentage);
+
+        let mut batch_nonces: ManagedVec<u64> = ManagedVec::new();
+        let mut batch_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>> =
+            ManagedVec::new();
+        let mut remaining_nonces: ManagedVec<u64> = ManagedVec::new();
+        let mut remaining_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>> =
+            ManagedVec::new();
+        for (nonce, strategy_attributes) in nonces.iter().zip(attributes.iter()) {
+            if strategy_attributes.direction == first_attributes.direction
+                && strategy_attributes.dca_token == first_attributes.dca_token
+                && strategy_attributes.quote_token == first_attributes.quote_token
+                && self
+                    .get_strategy_slippage_percentage(strategy_attributes.max_slippage_percentage)
+                    == first_slippage_percentage
+            {
+                batch_nonces.push(nonce);
+                batch_attributes.push(strategy_attributes.clone());
+            } else {
+                remaining_nonces.push(nonce);
+                remaining_attributes.push(strategy_attributes.clone());
+            }
+        }
+
+        *nonces = remaining_nonces;
+        *attributes = remaining_attributes;
+
+        (batch_nonces, batch_attributes)
+    }
+
+    /// Executes a single quote token to DCA token swap for a batch of strategies sharing the same
+    /// token pair and slippage and splits the tokens received among them, proportionally to the amount
+    /// each one spends. The amount each strategy spends is computed before the swap is aggregated:
+    /// its amount per swap times the scheduled intervals it buys for, or its value averaging amount,
+    /// multiplied when buying the dip.
+    ///
+    /// Returns the DCA token amount received from the swap.
+    fn execute_buy_batch(
+        &self,
+        caller: &ManagedAddress,
+        ts_millis: u64,
+        nonces: ManagedVec<u64>,
+        all_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>>,
+    ) -> BigUint {
+        let dca_token = all_attributes.get(0).dca_token.clone();
+        let quote_token = all_attributes.get(0).quote_token.clone();
+        let slippage_percentage =
+            self.get_strategy_slippage_percentage(all_attributes.get(0).max_slippage_percentage);
+        let mut all_intervals: ManagedVec<u64> = ManagedVec::new();
+        let mut all_amounts_spent: ManagedVec<BigUint> = ManagedVec::new();
+        let mut amount_to_swap = BigUint::zero();
+        for attributes in all_attributes.iter() {
+            let intervals = self.get_swap_intervals(&attributes, ts_millis);
+            let amount_spent = self.get_buy_amount(&attributes, intervals);
+            amount_to_swap += &amount_spent;
+            all_intervals.push(intervals);
+            all_amounts_spent.push(amount_spent);
+        }
+
+        if quote_token.is_egld() {
+            self.wrap_egld(&amount_to_swap);
+        }
+
+        let token_out = self.get_token_as_esdt(&dca_token);
         let amount_returned = self.execute_swap(
-            self.get_usdc_identifier(),
+            self.get_token_as_esdt(&quote_token),
             amount_to_swap.clone(),
             token_out.clone(),
-            self.get_final_slippage_percentage(),
+            slippage_percentage,
         );
 
-        let dca_token = self.dca_token().get();
         if token_out == self.get_wegld_identifier() && dca_token.is_egld() {
             self.unwrap_egld(&amount_returned);
         }
 
         let mut total_exact_amount_received = BigUint::zero();
 
-        for (nonce, mut attributes) in all_nonces.into_iter().zip(all_attributes.into_iter()) {
-            let proportion = attributes
-                .amount_per_swap
+        for (((nonce, mut attributes), intervals), amount_spent) in nonces
+            .into_iter()
+            .zip(all_attributes)
+            .zip(all_intervals)
+            .zip(all_amounts_spent)
+        {
+            let proportion = amount_spent
                 .clone()
                 .mul(MAX_PERCENTAGE)
                 .div(&amount_to_swap);
@@ -99,108 +485,220 @@ pub trait BotModule:
             let amount_received = amount_returned.clone().mul(&proportion).div(MAX_PERCENTAGE);
             total_exact_amount_received += &amount_received;
 
-            attributes.usdc_balance -= &attributes.amount_per_swap;
-            attributes.dca_token_balance += &amount_received;
+            attributes.quote_token_balance -= &amount_spent;
+            attributes.scheduled_buys += intervals;
+            attributes.total_spent += &amount_spent;
             attributes.last_executed_ts_millis = ts_millis;
-            attributes.buys.push(Swap {
-                usdc_amount: attributes.amount_per_swap.clone(),
-                dca_token_amount: amount_received.clone(),
-                timestamp_millis: ts_millis,
-            });
-            let updated_attributes_buffer = self.attributes_to_buffer(
-                attributes.amount_per_swap.clone(),
-                attributes.dca_frequency,
-                attributes.frequency_in_millis,
-                attributes.take_profit_percentage,
-                attributes.usdc_balance,
-                attributes.dca_token_balance,
-                attributes.last_executed_ts_millis,
-                attributes.buys,
-                attributes.sells,
+            attributes.next_buy_ts_millis = self.get_next_buy_ts_millis(&attributes, ts_millis);
+            self.add_buy(
+                &mut attributes,
+                Swap {
+                    quote_token_amount: amount_spent.clone(),
+                    dca_token_amount: amount_received.clone(),
+                    timestamp_millis: ts_millis,
+                },
+                &amount_spent,
             );
+            attributes.is_completed = self.is_strategy_completed(&attributes, ts_millis);
+            let updated_attributes_buffer = self.attributes_to_buffer(&attributes);
 
             self.strategy_token()
                 .nft_update_attributes(nonce, &updated_attributes_buffer);
 
-            self.event_buy_executed(nonce, &attributes.amount_per_swap, &amount_received);
+            self.event_buy_executed(nonce, &amount_spent, &amount_received);
+            if attributes.is_completed {
+                self.event_strategy_completed(nonce);
+            }
         }
 
         if amount_returned > total_exact_amount_received {
-            let dust_amount = amount_returned - total_exact_amount_received;
+            let dust_amount = &amount_returned - &total_exact_amount_received;
             self.tx()
-                .to(&caller)
+                .to(caller)
                 .egld_or_single_esdt(&dca_token, 0, &dust_amount)
                 .transfer();
         }
+
+        amount_returned
     }
 
-    /// Endpoint executed by the bot to perform take profit operations for multiple DCA strategies.
-    ///
-    /// Parameters:
-    /// - `nonces`: A list of nonces representing the DCA strategies to evaluate for take profit.
+    /// Executes a single DCA token to quote token swap for a batch of selling strategies sharing the same
+    /// token pair and slippage and splits the quote tokens received among them, proportionally to the DCA token
+    /// amount each one sells. The amount each strategy sells is computed before the swap is aggregated: its DCA
+    /// token amount per swap, or the DCA token amount worth its amount per swap, times the scheduled intervals it
+    /// sells for. The quote tokens received are kept in the strategy, less the profit fee sent to the caller,
+    /// which is taken on the profit over the share of the fee cost basis sold, if any.
     ///
-    /// The bot checks each strategy to see if the take profit condition is met based on the
-    /// DCA token balance and the defined take profit percentage. If the condition is met,
-    /// it aggregates the total amount to swap, performs the swap, deducts any applicable profit
-    /// fees, and updates each strategy's attributes accordingly.
-    ///
-    /// It will skip strategies that do not meet the take profit condition and will return any
-    /// dust amount to the caller. It will fail only if no strategies at all are eligible for take profit.
-    #[endpoint(takeProfit)]
-    fn take_profit(&self, nonces: MultiValueEncoded<u64>) {
-        let caller = self.blockchain().get_caller();
-        self.require_is_bot(&caller);
+    /// Returns the quote token amount received from the swap.
+    fn execute_scheduled_sell_batch(
+        &self,
+        caller: &ManagedAddress,
+        ts_millis: u64,
+        nonces: ManagedVec<u64>,
+        all_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>>,
+    ) -> BigUint {
+        let dca_token = all_attributes.get(0).dca_token.clone();
+        let quote_token = all_attributes.get(0).quote_token.clone();
+        let slippage_percentage =
+            self.get_strategy_slippage_percentage(all_attributes.get(0).max_slippage_percentage);
+        let dca_token_as_esdt = self.get_token_as_esdt(&dca_token);
+        let mut all_intervals: ManagedVec<u64> = ManagedVec::new();
+        let mut amounts_to_sell: ManagedVec<BigUint> = ManagedVec::new();
+        let mut amount_to_swap = BigUint::zero();
+        for attributes in all_attributes.iter() {
+            let intervals = self.get_swap_intervals(&attributes, ts_millis);
+            let amount_to_sell = self.get_scheduled_sell_amount(&attributes, intervals);
+            amount_to_swap += &amount_to_sell;
+            all_intervals.push(intervals);
+            amounts_to_sell.push(amount_to_sell);
+        }
 
-        let ts_millis = self
-            .blockchain()
-            .get_block_timestamp_millis()
-            .as_u64_millis();
+        if dca_token_as_esdt == self.get_wegld_identifier() && dca_token.is_egld() {
+            self.wrap_egld(&amount_to_swap);
+        }
 
-        let dca_token_as_esdt = self.get_dca_token_as_esdt();
+        let amount_returned = self.execute_swap(
+            dca_token_as_esdt,
+            amount_to_swap.clone(),
+            self.get_token_as_esdt(&quote_token),
+            slippage_percentage,
+        );
 
-        let mut amount_to_swap: BigUint = BigUint::zero();
-        let mut all_nonces: ManagedVec<u64> = ManagedVec::new();
-        let mut all_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>> = ManagedVec::new();
-        for nonce in nonces.into_iter() {
-            let attributes = self.get_strategy_token_attributes(nonce);
-            let dca_token_usdc_equivalent = self
-                .get_dca_token_usdc_equivalent(&dca_token_as_esdt, &attributes.dca_token_balance);
-
-            if attributes.dca_token_balance > 0
-                && &attributes.amount_per_swap * attributes.frequency_in_millis > 0
-                && self.is_in_profit(
-                    &dca_token_usdc_equivalent,
-                    attributes.take_profit_percentage,
-                    &attributes.buys,
-                    &attributes.sells,
-                )
-            {
-                amount_to_swap += &attributes.dca_token_balance;
-                all_attributes.push(attributes);
-                all_nonces.push(nonce);
+        if quote_token.is_egld() {
+            self.unwrap_egld(&amount_returned);
+        }
+
+        let profit_fee_percentage = self.profit_fee_percentage().get();
+        let mut total_fee = BigUint::zero();
+        let mut total_exact_amount_received = BigUint::zero();
+
+        for (((nonce, mut attributes), intervals), dca_token_amount_sold) in nonces
+            .into_iter()
+            .zip(all_attributes)
+            .zip(all_intervals)
+            .zip(amounts_to_sell)
+        {
+            let proportion = dca_token_amount_sold
+                .clone()
+                .mul(MAX_PERCENTAGE)
+                .div(&amount_to_swap);
+
+            let mut amount_received = amount_returned.clone().mul(&proportion).div(MAX_PERCENTAGE);
+            total_exact_amount_received += &amount_received;
+
+            let fee_cost_basis_sold =
+                self.remove_from_position(&mut attributes, &dca_token_amount_sold);
+            let fee = self
+                .calculate_profit(&amount_received, &fee_cost_basis_sold)
+                .mul(profit_fee_percentage)
+                .div(BigUint::from(MAX_PERCENTAGE));
+            amount_received -= &fee;
+            total_fee += fee;
+
+            attributes.quote_token_balance += &amount_received;
+            attributes.take_profit_high_water_mark = BigUint::zero();
+            attributes.scheduled_sells += intervals;
+            attributes.last_executed_ts_millis = ts_millis;
+            attributes.next_buy_ts_millis = self.get_next_buy_ts_millis(&attributes, ts_millis);
+            self.add_sell(
+                &mut attributes,
+                Swap {
+                    quote_token_amount: amount_received.clone(),
+                    dca_token_amount: dca_token_amount_sold.clone(),
+                    timestamp_millis: ts_millis,
+                },
+            );
+            attributes.is_completed = self.is_strategy_completed(&attributes, ts_millis);
+            let updated_attributes_buffer = self.attributes_to_buffer(&attributes);
+
+            self.strategy_token()
+                .nft_update_attributes(nonce, &updated_attributes_buffer);
+
+            self.event_scheduled_sell_executed(nonce, &dca_token_amount_sold, &amount_received);
+            if attributes.is_completed {
+                self.event_strategy_completed(nonce);
             }
         }
 
-        require!(!all_nonces.is_empty(), "No valid strategies to execute");
+        if total_fee > 0 {
+            self.tx()
+                .to(caller)
+                .egld_or_single_esdt(&quote_token, 0, &total_fee)
+                .transfer();
+        };
 
-        if dca_token_as_esdt == self.get_wegld_identifier() && self.dca_token().get().is_egld() {
+        if amount_returned > total_exact_amount_received {
+            let dust_amount = &amount_returned - &total_exact_amount_received;
+            self.tx()
+                .to(caller)
+                .egld_or_single_esdt(&quote_token, 0, &dust_amount)
+                .transfer();
+        }
+
+        amount_returned
+    }
+
+    /// Executes a single DCA token to quote token swap for a batch of strategies sharing the same
+    /// token pair and slippage, splits the quote tokens received among them proportionally to the DCA token
+    /// amount each one sells and sends the profit fees to the caller.
+    ///
+    /// A take profit sells the take profit sell percentage of each DCA token balance and applies each
+    /// strategy's post take profit action to the quote tokens it receives. A stop loss
+    /// (`is_stop_loss`) sells the whole balance, takes no profit fee, always keeps the quote tokens
+    /// in the strategy and emits `stopLossExecuted` instead of `sellExecuted`.
+    ///
+    /// Proceeds that cannot be sent to the strategy owner (e.g., a frozen token) are kept in the
+    /// strategy's quote token balance, so one owner cannot block the sells of the whole batch.
+    ///
+    /// Returns the quote token amount received from the swap.
+    fn execute_sell_batch(
+        &self,
+        caller: &ManagedAddress,
+        ts_millis: u64,
+        nonces: ManagedVec<u64>,
+        all_attributes: ManagedVec<StrategyTokenAttributes<Self::Api>>,
+        is_stop_loss: bool,
+    ) -> BigUint {
+        let dca_token = all_attributes.get(0).dca_token.clone();
+        let quote_token = all_attributes.get(0).quote_token.clone();
+        let slippage_percentage =
+            self.get_strategy_slippage_percentage(all_attributes.get(0).max_slippage_percentage);
+        let dca_token_as_esdt = self.get_token_as_esdt(&dca_token);
+        let mut amounts_to_sell: ManagedVec<BigUint> = ManagedVec::new();
+        for attributes in all_attributes.iter() {
+            if is_stop_loss {
+                amounts_to_sell.push(attributes.dca_token_balance.clone());
+            } else {
+                amounts_to_sell.push(self.get_take_profit_sell_amount(&attributes));
+            }
+        }
+        let amount_to_swap = amounts_to_sell
+            .iter()
+            .fold(BigUint::zero(), |acc, amount| acc + &*amount);
+
+        if dca_token_as_esdt == self.get_wegld_identifier() && dca_token.is_egld() {
             self.wrap_egld(&amount_to_swap);
         }
 
         let amount_returned = self.execute_swap(
             dca_token_as_esdt.clone(),
             amount_to_swap.clone(),
-            self.get_usdc_identifier(),
-            self.get_final_slippage_percentage(),
+            self.get_token_as_esdt(&quote_token),
+            slippage_percentage,
         );
 
+        if quote_token.is_egld() {
+            self.unwrap_egld(&amount_returned);
+        }
+
         let profit_fee_percentage = self.profit_fee_percentage().get();
         let mut total_fee = BigUint::zero();
         let mut total_exact_amount_received = BigUint::zero();
 
-        for (nonce, mut attributes) in all_nonces.into_iter().zip(all_attributes.into_iter()) {
-            let proportion = attributes
-                .dca_token_balance
+        for ((nonce, mut attributes), dca_token_amount_sold) in
+            nonces.into_iter().zip(all_attributes).zip(amounts_to_sell)
+        {
+            let proportion = dca_token_amount_sold
                 .clone()
                 .mul(MAX_PERCENTAGE)
                 .div(&amount_to_swap);
@@ -211,128 +709,182 @@ pub trait BotModule:
                 .div(&BigUint::from(MAX_PERCENTAGE));
             total_exact_amount_received += &amount_received;
 
-            let fee = self
-                .calculate_profit(&amount_received, &attributes.buys, &attributes.sells)
-                .mul(profit_fee_percentage)
-                .div(BigUint::from(MAX_PERCENTAGE));
+            let fee_cost_basis_sold =
+                self.remove_from_position(&mut attributes, &dca_token_amount_sold);
+            let fee = if is_stop_loss {
+                BigUint::zero()
+            } else {
+                self.calculate_profit(&amount_received, &fee_cost_basis_sold)
+                    .mul(profit_fee_percentage)
+                    .div(BigUint::from(MAX_PERCENTAGE))
+            };
 
             amount_received -= &fee;
             total_fee += fee;
             total_exact_amount_received += &amount_received;
 
-            attributes.usdc_balance += &amount_received;
+            let post_take_profit_action = if is_stop_loss {
+                PostTakeProfitAction::Reinvest
+            } else {
+                attributes.post_take_profit_action.clone()
+            };
+            match post_take_profit_action {
+                PostTakeProfitAction::Reinvest => {
+                    attributes.quote_token_balance += &amount_received;
+                }
+                PostTakeProfitAction::Pause => {
+                    attributes.quote_token_balance += &amount_received;
+                    attributes.is_paused = true;
+                }
+                PostTakeProfitAction::Withdraw => {
+                    let is_transferred = amount_received == 0
+                        || self
+                            .tx()
+                            .to(&attributes.owner)
+                            .egld_or_single_esdt(&quote_token, 0, &amount_received)
+                            .transfer_fallible()
+                            .is_ok();
+                    if is_transferred {
+                        self.event_take_profit_proceeds_withdrawn(
+                            nonce,
+                            &attributes.owner,
+                            &amount_received,
+                        );
+                    } else {
+                        attributes.quote_token_balance += &amount_received;
+                        self.event_take_profit_proceeds_kept(
+                            nonce,
+                            &attributes.owner,
+                            &amount_received,
+                        );
+                    }
+                }
+            }
             attributes.last_executed_ts_millis = ts_millis;
-            attributes.sells.push(Swap {
-                usdc_amount: amount_received.clone(),
-                dca_token_amount: attributes.dca_token_balance.clone(),
-                timestamp_millis: ts_millis,
-            });
-            attributes.dca_token_balance = BigUint::zero();
-
-            let updated_attributes_buffer = self.attributes_to_buffer(
-                attributes.amount_per_swap.clone(),
-                attributes.dca_frequency,
-                attributes.frequency_in_millis,
-                attributes.take_profit_percentage,
-                attributes.usdc_balance,
-                attributes.dca_token_balance.clone(),
-                attributes.last_executed_ts_millis,
-                attributes.buys,
-                attributes.sells,
+            attributes.take_profit_high_water_mark = BigUint::zero();
+            self.add_sell(
+                &mut attributes,
+                Swap {
+                    quote_token_amount: amount_received.clone(),
+                    dca_token_amount: dca_token_amount_sold.clone(),
+                    timestamp_millis: ts_millis,
+                },
             );
 
+            let updated_attributes_buffer = self.attributes_to_buffer(&attributes);
+
             self.strategy_token()
                 .nft_update_attributes(nonce, &updated_attributes_buffer);
 
-            self.event_sell_executed(nonce, &attributes.dca_token_balance, &amount_received);
+            if is_stop_loss {
+                self.event_stop_loss_executed(nonce, &dca_token_amount_sold, &amount_received);
+            } else {
+                self.event_sell_executed(nonce, &dca_token_amount_sold, &amount_received);
+            }
         }
 
         if total_fee > 0 {
             self.tx()
-                .to(&caller)
-                .single_esdt(&self.get_usdc_identifier(), 0, &total_fee)
+                .to(caller)
+                .egld_or_single_esdt(&quote_token, 0, &total_fee)
                 .transfer();
         };
 
         if amount_returned > total_exact_amount_received {
-            let dust_amount = amount_returned - total_exact_amount_received;
+            let dust_amount = &amount_returned - &total_exact_amount_received;
             self.tx()
-                .to(&caller)
-                .single_esdt(&self.get_usdc_identifier(), 0, &dust_amount)
+                .to(caller)
+                .egld_or_single_esdt(&quote_token, 0, &dust_amount)
                 .transfer();
         }
-    }
 
-    // === Views ===
-
-    /// Returns true if the given address can execute bot operations.
-    ///
-    /// This includes the bot address and any admin addresses (as a fallback mechanism).
-    #[view(isBot)]
-    fn is_bot(&self, address: &ManagedAddress) -> bool {
-        &self.bot_address().get() == address || self.admins().contains(address)
+        amount_returned
     }
 
-    // #[view(getStrategyTokenTakeProfitInfo)]
-    // fn get_strategy_token_take_profit_info(&self, nonce: u64) -> TestOutupt<Self::Api> {
-    //     let attributes = self.get_strategy_token_attributes(nonce);
-    //     let dca_token_as_esdt = self.get_dca_token_as_esdt();
-    //     let dca_token_equivalent =
-    //         self.get_dca_token_usdc_equivalent(&dca_token_as_esdt, &attributes.dca_token_balance);
-
-    //     let is_met = self.is_take_profit_condition_met(
-    //         &dca_token_equivalent,
-    //         attributes.take_profit_percentage,
-    //         &attributes.buys,
-    //         &attributes.sells,
-    //     );
-
-    //     TestOutupt::from((
-    //         attributes.dca_token_balance,
-    //         dca_token_equivalent,
-    //         is_met,
-    //         attributes.take_profit_percentage,
-    //     ))
-    // }
-
-    // === Private ===
-
     /// Calculates the profit from a take profit operation.
     ///
     /// The profit is calculated as the difference between the amount received
-    /// and the total buys made since the last sell operation.
+    /// and the fee cost basis of the DCA tokens sold.
     ///
     /// ### Arguments
     /// * `amount_received` - The amount received from the sell.
-    /// * `buys` - The list of buy operations.
-    /// * `sells` - The list of sell operations.
+    /// * `fee_cost_basis_sold` - The share of the position's fee cost basis matching the DCA tokens sold.
     ///
     /// ### Returns
     /// The calculated profit amount.
     fn calculate_profit(
         &self,
         amount_received: &BigUint,
-        buys: &ManagedVec<Swap<Self::Api>>,
-        sells: &ManagedVec<Swap<Self::Api>>,
+        fee_cost_basis_sold: &BigUint,
     ) -> BigUint {
-        let last_sell_ts_millis = sells
-            .iter()
-            .next_back()
-            .unwrap_or(Swap::default().as_ref())
-            .timestamp_millis;
-
-        let total_buys_after_last_sell = buys
-            .iter()
-            .filter(|swap| swap.timestamp_millis > last_sell_ts_millis)
-            .fold(BigUint::zero(), |acc, swap| acc + &swap.usdc_amount);
-
-        if amount_received > &total_buys_after_last_sell {
-            amount_received - &total_buys_after_last_sell
+        if amount_received > fee_cost_basis_sold {
+            amount_received - fee_cost_basis_sold
         } else {
             BigUint::zero()
         }
     }
 
+    /// Adds the output of a batch swap to the total output of its token pair.
+    fn add_amount_out(
+        &self,
+        amounts_out: &mut ManagedVec<SwapMinAmountOut<Self::Api>>,
+        token_in: &EgldOrEsdtTokenIdentifier,
+        token_out: &EgldOrEsdtTokenIdentifier,
+        amount_out: &BigUint,
+    ) {
+        let pair_index = amounts_out.iter().position(|pair_amount_out| {
+            &pair_amount_out.token_in == token_in && &pair_amount_out.token_out == token_out
+        });
+        match pair_index {
+            Some(index) => {
+                let mut pair_amount_out = amounts_out.get(index).clone();
+                pair_amount_out.amount += amount_out;
+                let _ = amounts_out.set(index, pair_amount_out);
+            }
+            None => amounts_out.push(SwapMinAmountOut {
+                token_in: token_in.clone(),
+                token_out: token_out.clone(),
+                amount: amount_out.clone(),
+            }),
+        }
+    }
+
+    /// Requires the total swap output of each token pair, summed across its slippage batches, to meet
+    /// the minimum the bot expects for that pair, if any, otherwise fails. Also fails if a pair has more
+    /// than one minimum, or a minimum is given for a pair the call did not swap.
+    fn require_min_amounts_out(
+        &self,
+        min_amounts_out: &ManagedVec<SwapMinAmountOut<Self::Api>>,
+        amounts_out: &ManagedVec<SwapMinAmountOut<Self::Api>>,
+    ) {
+        for (index, min_amount_out) in min_amounts_out.iter().enumerate() {
+            let is_duplicate = min_amounts_out.iter().take(index).any(|previous| {
+                previous.token_in == min_amount_out.token_in
+                    && previous.token_out == min_amount_out.token_out
+            });
+            let pair_amount_out = amounts_out.iter().find(|pair_amount_out| {
+                pair_amount_out.token_in == min_amount_out.token_in
+                    && pair_amount_out.token_out == min_amount_out.token_out
+            });
+            require!(
+                !is_duplicate && pair_amount_out.is_some(),
+                ERROR_INVALID_MIN_AMOUNTS_OUT
+            );
+            require!(
+                pair_amount_out.unwrap().amount >= min_amount_out.amount,
+                ERROR_MIN_AMOUNT_OUT_NOT_MET
+            );
+        }
+    }
+
+    /// Requires the deadline, if any (non-zero), not to have passed, otherwise fails.
+    fn require_deadline_not_passed(&self, deadline_ts_millis: TimestampInMillis, ts_millis: u64) {
+        require!(
+            deadline_ts_millis == 0 || ts_millis <= deadline_ts_millis,
+            ERROR_DEADLINE_PASSED
+        );
+    }
+
     /// Requires the given address to be the bot or an admin, otherwise fails.
     fn require_is_bot(&self, address: &ManagedAddress) {
         require!(&self.is_bot(address), ERROR_NOT_BOT);
@@ -354,15 +906,57 @@ pub trait BotModule:
     fn event_buy_executed(
         &self,
         #[indexed] nonce: u64,
-        #[indexed] usdc_amount: &BigUint,
+        #[indexed] quote_token_amount: &BigUint,
         #[indexed] dca_token_amount: &BigUint,
     );
 
+    #[event("strategyCompleted")]
+    fn event_strategy_completed(&self, #[indexed] nonce: u64);
+
     #[event("sellExecuted")]
     fn event_sell_executed(
         &self,
         #[indexed] nonce: u64,
         #[indexed] dca_token_amount: &BigUint,
-        #[indexed] usdc_amount: &BigUint,
+        #[indexed] quote_token_amount: &BigUint,
+    );
+
+    #[event("scheduledSellExecuted")]
+    fn event_scheduled_sell_executed(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] dca_token_amount: &BigUint,
+        #[indexed] quote_token_amount: &BigUint,
+    );
+
+    #[event("takeProfitHighWaterMarkUpdated")]
+    fn event_take_profit_high_water_mark_updated(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] high_water_mark: &BigUint,
+    );
+
+    #[event("takeProfitProceedsWithdrawn")]
+    fn event_take_profit_proceeds_withdrawn(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] quote_token_amount: &BigUint,
+    );
+
+    #[event("takeProfitProceedsKept")]
+    fn event_take_profit_proceeds_kept(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] quote_token_amount: &BigUint,
+    );
+
+    #[event("stopLossExecuted")]
+    fn event_stop_loss_executed(
+        &self,
+        #[indexed] nonce: u64,
+        #[indexed] dca_token_amount: &BigUint,
+        #[indexed] quote_token_amount: &BigUint,
     );
 }
@@ -32,6 +32,7 @@ pub trait Dcai:
     admins::AdminsModule
     + pause::PauseModule
     + xexchange::pair_module::XExchangePairModule
+    + xexchange::safe_price_module::SafePriceModule
     + xexchange::router_module::XExchangeRouterModule
     + xexchange::wrapper_module::WrapperModule
     + bot::BotModule
@@ -40,15 +41,65 @@ pub trait Dcai:
 {
     /// Initializes the smart contract.
     ///
-    /// Adds the caller (deployer) as an admin.
+    /// Adds the caller (deployer) as an admin and stores the network specific
+    /// xExchange addresses and token identifiers.
+    ///
+    /// ### Arguments
+    /// * `xexchange_router_sc_address` - The xExchange router contract address.
+    /// * `wrapper_sc_address` - The EGLD/WEGLD wrapper contract address.
+    /// * `safe_price_view_sc_address` - The xExchange safe price view contract address.
+    /// * `usdc_identifier` - The USDC token identifier.
+    /// * `wegld_identifier` - The WEGLD token identifier.
     #[init]
-    fn init(&self) {
+    fn init(
+        &self,
+        xexchange_router_sc_address: ManagedAddress,
+        wrapper_sc_address: ManagedAddress,
+        safe_price_view_sc_address: ManagedAddress,
+        usdc_identifier: TokenIdentifier,
+        wegld_identifier: TokenIdentifier,
+    ) {
         self.admins().insert(self.blockchain().get_caller());
+
+        self.set_xexchange_router_sc_address(xexchange_router_sc_address);
+        self.set_wrapper_sc_address(wrapper_sc_address);
+        self.set_safe_price_view_sc_address(safe_price_view_sc_address);
+        self.set_usdc_identifier(usdc_identifier);
+        self.set_wegld_identifier(wegld_identifier);
     }
 
     /// Upgrade handler for the smart contract.
     ///
-    /// Called when the contract is upgraded.
+    /// Called when the contract is upgraded. Stores the network specific xExchange
+    /// addresses and token identifiers again, so an upgrade can also repoint them.
+    ///
+    /// Upgrading the single DCA token contract version moves its DCA token and min amount per swap
+    /// to the DCA token and quote token allowlists, USDC being its quote token. Its strategies are
+    /// converted when next read and stored in the current format when next updated.
+    ///
+    /// ### Arguments
+    /// * `xexchange_router_sc_address` - The xExchange router contract address.
+    /// * `wrapper_sc_address` - The EGLD/WEGLD wrapper contract address.
+    /// * `safe_price_view_sc_address` - The xExchange safe price view contract address.
+    /// * `usdc_identifier` - The USDC token identifier.
+    /// * `wegld_identifier` - The WEGLD token identifier.
+    /// * `legacy_dca_token_decimals` - The decimals of the DCA token, required when upgrading the single
+    ///   DCA token contract version.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(
+        &self,
+        xexchange_router_sc_address: ManagedAddress,
+        wrapper_sc_address: ManagedAddress,
+        safe_price_view_sc_address: ManagedAddress,
+        usdc_identifier: TokenIdentifier,
+        wegld_identifier: TokenIdentifier,
+        legacy_dca_token_decimals: OptionalValue<u32>,
+    ) {
+        self.set_xexchange_router_sc_address(xexchange_router_sc_address);
+        self.set_wrapper_sc_address(wrapper_sc_address);
+        self.set_safe_price_view_sc_address(safe_price_view_sc_address);
+        self.set_usdc_identifier(usdc_identifier);
+        self.set_wegld_identifier(wegld_identifier);
+        self.migrate_single_dca_token_setup(legacy_dca_token_decimals);
+    }
 }
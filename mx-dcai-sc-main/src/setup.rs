@@ -10,27 +10,30 @@ use structs::*;
 /// * endpoints where the admins can update the DCA strategy parameters
 /// * a view to get the DCA setup parameters
 #[multiversx_sc::module]
-pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
+pub trait SetupModule:
+    crate::admins::AdminsModule
+    + crate::pause::PauseModule
+    + crate::xexchange::pair_module::XExchangePairModule
+{
     // === Endpoints ===
 
     /// Initial setup endpoint for the DCA contract.
     ///
     /// Only the contract owner can call this endpoint.
     /// This must be called once to configure the DCA parameters before the contract can be used.
+    /// The tokens users can DCA into are managed separately through `addAllowedDcaTokens`.
     ///
     /// ### Arguments
-    /// * `dca_token` - The token to be accumulated through DCA (e.g., EGLD or an ESDT).
     /// * `strategy_token_ticker` - The ticker for the strategy NFT token (max 5 characters).
     /// * `strategy_token_display_name` - The display name for the strategy NFT token.
-    /// * `min_amount_per_swap` - The minimum USDC amount allowed per DCA swap.
+    /// * `min_amount_per_swap` - The minimum USDC amount allowed per DCA swap. USDC is registered as the first allowed quote token.
     /// * `profit_fee_percentage` - The fee percentage taken from profits on take profit operations.
-    /// * `allowed_frequencies` - The allowed DCA frequencies (e.g., daily, weekly) with their durations in milliseconds.
+    /// * `allowed_frequencies` - The named DCA frequency presets (e.g., daily, weekly) with their durations in milliseconds.
     #[only_owner]
     #[payable]
     #[endpoint(setup)]
     fn setup(
         &self,
-        dca_token: EgldOrEsdtTokenIdentifier,
         strategy_token_ticker: ManagedBuffer,
         strategy_token_display_name: ManagedBuffer,
         min_amount_per_swap: BigUint,
@@ -39,11 +42,7 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
     ) {
         self.require_not_setup_yet();
 
-        self.event_dca_token_set(&dca_token);
-        self.dca_token().set(dca_token.clone());
-
-        self.event_min_amount_per_swap_set(&min_amount_per_swap);
-        self.min_amount_per_swap().set(min_amount_per_swap.clone());
+        self.add_usdc_as_quote_token(&min_amount_per_swap);
 
         self.event_allowed_frequencies_added(&allowed_frequencies);
         for freq in allowed_frequencies.clone().into_iter() {
@@ -68,7 +67,6 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
             0,
             Option::Some(self.callbacks().strategy_token_issuance_callback(
                 &self.blockchain().get_owner_address(),
-                dca_token,
                 min_amount_per_swap,
                 profit_fee_percentage,
                 allowed_frequencies,
@@ -76,35 +74,84 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
         );
     }
 
-    /// Sets the DCA token.
+    /// Adds tokens to the whitelist of tokens that strategies can accumulate through DCA,
+    /// or updates their decimals.
+    ///
+    /// Only admins can call this endpoint.
+    /// The decimals define the whole token unit that DCA token prices are quoted for.
+    ///
+    /// ### Arguments
+    /// * `dca_tokens` - A list of token (e.g., EGLD or ESDTs) - decimals pairs to allow.
+    #[endpoint(addAllowedDcaTokens)]
+    fn add_allowed_dca_tokens(&self, dca_tokens: MultiValueEncoded<DcaTokenDecimals<Self::Api>>) {
+        self.require_is_admin(&self.blockchain().get_caller());
+        self.event_allowed_dca_tokens_added(&dca_tokens);
+        for dca_token in dca_tokens.into_iter() {
+            let (token, decimals) = dca_token.into_tuple();
+            require!(decimals <= WAD_DECIMALS as u32, ERROR_INVALID_DCA_TOKEN);
+
+            self.dca_token_decimals(&token).set(decimals);
+            self.allowed_dca_tokens().insert(token);
+        }
+    }
+
+    /// Removes tokens from the DCA token whitelist.
     ///
     /// Only admins can call this endpoint.
+    /// Existing strategies keep running on their token; only new strategies are affected.
     ///
     /// ### Arguments
-    /// * `dca_token` - The token to be accumulated through DCA.
-    #[endpoint(setToken)]
-    fn set_dca_token(&self, dca_token: EgldOrEsdtTokenIdentifier) {
+    /// * `dca_tokens` - A list of tokens to remove.
+    #[endpoint(removeAllowedDcaTokens)]
+    fn remove_allowed_dca_tokens(&self, dca_tokens: MultiValueEncoded<EgldOrEsdtTokenIdentifier>) {
         self.require_is_admin(&self.blockchain().get_caller());
-        self.event_dca_token_set(&dca_token);
-        self.dca_token().set(dca_token);
+        self.event_allowed_dca_tokens_removed(&dca_tokens);
+        for dca_token in dca_tokens.into_iter() {
+            self.allowed_dca_tokens().swap_remove(&dca_token);
+        }
     }
 
-    /// Sets the minimum amount per swap.
+    /// Adds quote tokens that strategies can be funded with, or updates their minimum amount per swap.
     ///
     /// Only admins can call this endpoint.
     ///
     /// ### Arguments
-    /// * `min_amount_per_swap` - The minimum USDC amount per DCA swap.
-    #[endpoint(setMinAmountPerSwap)]
-    fn set_min_amount_per_swap(&self, min_amount_per_swap: BigUint) {
+    /// * `quote_tokens` - A list of quote token - minimum amount per swap pairs to add.
+    #[endpoint(addAllowedQuoteTokens)]
+    fn add_allowed_quote_tokens(
+        &self,
+        quote_tokens: MultiValueEncoded<QuoteTokenMinAmountPerSwap<Self::Api>>,
+    ) {
         self.require_is_admin(&self.blockchain().get_caller());
-        self.event_min_amount_per_swap_set(&min_amount_per_swap);
-        self.min_amount_per_swap().set(min_amount_per_swap);
+        self.event_allowed_quote_tokens_added(&quote_tokens);
+        for quote_token in quote_tokens.into_iter() {
+            let (token, min_amount_per_swap) = quote_token.into_tuple();
+            self.allowed_quote_tokens()
+                .insert(token, min_amount_per_swap);
+        }
     }
 
-    /// Adds new allowed DCA frequencies.
+    /// Removes allowed quote tokens.
     ///
     /// Only admins can call this endpoint.
+    /// Existing strategies keep running on their quote token; only new strategies are affected.
+    ///
+    /// ### Arguments
+    /// * `quote_tokens` - A list of quote tokens to remove.
+    #[endpoint(removeAllowedQuoteTokens)]
+    fn remove_allowed_quote_tokens(&self, quote_tokens: MultiValueEncoded<QuoteToken<Self::Api>>) {
+        self.require_is_admin(&self.blockchain().get_caller());
+        self.event_allowed_quote_tokens_removed(&quote_tokens);
+        for quote_token in quote_tokens.into_iter() {
+            self.allowed_quote_tokens().remove(&quote_token);
+        }
+    }
+
+    /// Adds new named DCA frequency presets.
+    ///
+    /// Only admins can call this endpoint.
+    /// Presets name the durations shown to users. Until frequency duration bounds are set,
+    /// they also define the range of durations strategies can use.
     ///
     /// ### Arguments
     /// * `frequencies` - A list of frequency-duration pairs to add.
@@ -122,9 +169,12 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
         }
     }
 
-    /// Removes allowed DCA frequencies.
+    /// Removes named DCA frequency presets.
     ///
     /// Only admins can call this endpoint.
+    /// Existing strategies keep running on their frequency duration; only the display name is lost.
+    /// The last preset can only be removed once frequency duration bounds are set, since the presets
+    /// otherwise define the range of durations.
     ///
     /// ### Arguments
     /// * `frequencies` - A list of frequencies to remove.
@@ -135,6 +185,7 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
         for frequency in frequencies.into_iter() {
             self.allowed_frequencies().remove(&frequency);
         }
+        require!(self.has_frequency_durations(), ERROR_LAST_FREQUENCY_PRESET);
     }
 
     /// Sets the profit fee percentage.
@@ -165,17 +216,127 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
             .set(custom_slippage_percentage);
     }
 
+    /// Sets the cap on the max slippage percentage users can choose for their strategies.
+    ///
+    /// Only admins can call this endpoint.
+    /// If not set, the default cap will be used. Existing strategies keep their chosen slippage.
+    ///
+    /// ### Arguments
+    /// * `max_slippage_percentage_cap` - The highest max slippage percentage a strategy can have.
+    #[endpoint(setMaxSlippagePercentageCap)]
+    fn set_max_slippage_percentage_cap(&self, max_slippage_percentage_cap: u64) {
+        self.require_is_admin(&self.blockchain().get_caller());
+        require!(
+            max_slippage_percentage_cap < MAX_PERCENTAGE,
+            ERROR_INVALID_PERCENTAGE
+        );
+
+        self.event_max_slippage_percentage_cap_set(max_slippage_percentage_cap);
+        self.max_slippage_percentage_cap()
+            .set(max_slippage_percentage_cap);
+    }
+
+    /// Sets the minimum initial deposit required to create a strategy funded with the given quote token.
+    ///
+    /// Only admins can call this endpoint.
+    /// A zero amount lets strategies be created without an initial deposit.
+    ///
+    /// ### Arguments
+    /// * `quote_token` - An allowed quote token.
+    /// * `min_initial_deposit` - The minimum quote token amount paid to `createStrategy`.
+    #[endpoint(setMinInitialDeposit)]
+    fn set_min_initial_deposit(
+        &self,
+        quote_token: QuoteToken<Self::Api>,
+        min_initial_deposit: BigUint,
+    ) {
+        self.require_is_admin(&self.blockchain().get_caller());
+        self.get_min_amount_per_swap_and_require_valid(&quote_token);
+
+        self.event_min_initial_deposit_set(&quote_token, &min_initial_deposit);
+        self.min_initial_deposit(&quote_token)
+            .set(min_initial_deposit);
+    }
+
+    /// Sets the range of frequency durations users can choose for their strategies.
+    ///
+    /// Only admins can call this endpoint.
+    /// If not set, the range goes from the shortest to the longest named frequency preset.
+    /// Existing strategies keep their frequency duration.
+    ///
+    /// ### Arguments
+    /// * `min_duration_in_millis` - The shortest frequency duration in milliseconds.
+    /// * `max_duration_in_millis` - The longest frequency duration in milliseconds.
+    #[endpoint(setFrequencyDurationBounds)]
+    fn set_frequency_duration_bounds(
+        &self,
+        min_duration_in_millis: DurationInMillis,
+        max_duration_in_millis: DurationInMillis,
+    ) {
+        self.require_is_admin(&self.blockchain().get_caller());
+        require!(
+            min_duration_in_millis > 0 && min_duration_in_millis <= max_duration_in_millis,
+            ERROR_INVALID_FREQUENCY_BOUNDS
+        );
+
+        self.event_frequency_duration_bounds_set(min_duration_in_millis, max_duration_in_millis);
+        self.frequency_duration_bounds()
+            .set((min_duration_in_millis, max_duration_in_millis));
+    }
+
     // === Views ===
 
+    /// Returns the shortest and longest frequency durations in milliseconds a strategy can use
+    /// (custom if set, otherwise the range of the named frequency presets).
+    #[view(getFrequencyDurationBounds)]
+    fn get_frequency_duration_bounds(&self) -> MultiValue2<DurationInMillis, DurationInMillis> {
+        if !self.frequency_duration_bounds().is_empty() {
+            return MultiValue2::from(self.frequency_duration_bounds().get());
+        }
+
+        let mut bounds: Option<(DurationInMillis, DurationInMillis)> = None;
+        for duration_in_millis in self.allowed_frequencies().values() {
+            bounds = match bounds {
+                Some((min, max)) => {
+                    Some((min.min(duration_in_millis), max.max(duration_in_millis)))
+                }
+                None => Some((duration_in_millis, duration_in_millis)),
+            };
+        }
+
+        MultiValue2::from(bounds.unwrap_or((0, 0)))
+    }
+
+    /// Returns the cap on the max slippage percentage of a strategy (custom if set, otherwise default).
+    #[view(getMaxSlippagePercentageCap)]
+    fn get_final_max_slippage_percentage_cap(&self) -> u64 {
+        if self.max_slippage_percentage_cap().is_empty() {
+            DEFAULT_MAX_SLIPPAGE_CAP
+        } else {
+            self.max_slippage_percentage_cap().get()
+        }
+    }
+
+    /// Returns the decimals of a DCA token, recorded when it was allowed, and kept after it is removed
+    /// for the strategies still running on it. Fails for a token that was never allowed.
+    #[view(getDcaTokenDecimals)]
+    fn get_dca_token_decimals(&self, dca_token: &EgldOrEsdtTokenIdentifier) -> TokenDecimals {
+        require!(
+            !self.dca_token_decimals(dca_token).is_empty(),
+            ERROR_INVALID_DCA_TOKEN
+        );
+        self.dca_token_decimals(dca_token).get()
+    }
+
     /// View to get the DCA setup parameters.
     ///
     /// Returns a `DcaSetup` multi-value containing:
-    /// 1. The DCA token identifier.
-    /// 1. The minimum amount per swap.
+    /// 1. The allowed DCA token identifiers.
+    /// 1. The allowed quote token identifiers (see `getAllowedQuoteTokens` for their minimum amount per swap).
     /// 1. The strategy token identifier.
     /// 1. The profit fee percentage.
     /// 1. The final slippage percentage to be used in swaps (either custom or default).
-    /// 1. The allowed frequencies as a multi-value encoded of `DcaFrequencyInMillis` (frequency string and duration in millis).
+    /// 1. The named frequency presets as a multi-value encoded of `DcaFrequencyInMillis` (frequency string and duration in millis).
     #[view(getSetup)]
     fn get_setup(&self) -> DcaSetup<Self::Api> {
         let mut allowed_frequencies_vec: MultiValueEncoded<DcaFrequencyInMillis<Self::Api>> =
@@ -185,8 +346,8 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
         }
 
         DcaSetup::from((
-            self.dca_token().get(),
-            self.min_amount_per_swap().get(),
+            self.allowed_dca_tokens().iter().collect(),
+            self.allowed_quote_tokens().keys().collect(),
             self.strategy_token().get_token_id(),
             self.profit_fee_percentage().get(),
             self.get_final_slippage_percentage(),
@@ -198,11 +359,35 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
 
     // === Private ===
 
+    /// Moves the settings of the single DCA token contract version to the allowlists, if any: its DCA token,
+    /// with the given decimals, and USDC as quote token with its min amount per swap. The old settings are
+    /// cleared, and the DCA token is kept as the one of the strategies created by that version.
+    fn migrate_single_dca_token_setup(&self, legacy_dca_token_decimals: OptionalValue<u32>) {
+        if self.single_dca_token().is_empty() {
+            return;
+        }
+
+        let decimals = legacy_dca_token_decimals
+            .into_option()
+            .unwrap_or_else(|| sc_panic!(ERROR_INVALID_DCA_TOKEN));
+        require!(decimals <= WAD_DECIMALS as u32, ERROR_INVALID_DCA_TOKEN);
+
+        let dca_token = self.single_dca_token().take();
+        self.dca_token_decimals(&dca_token).set(decimals);
+        self.allowed_dca_tokens().insert(dca_token.clone());
+        self.legacy_dca_token().set(dca_token);
+
+        let min_amount_per_swap = self.single_min_amount_per_swap().take();
+        self.allowed_quote_tokens().insert(
+            EgldOrEsdtTokenIdentifier::esdt(self.usdc_identifier().get()),
+            min_amount_per_swap,
+        );
+    }
+
     /// Validates that the initial setup has not been performed yet.
     fn require_not_setup_yet(&self) {
         require!(
-            self.dca_token().is_empty()
-                && self.min_amount_per_swap().is_empty()
+            self.allowed_quote_tokens().is_empty()
                 && self.allowed_frequencies().is_empty()
                 && self.strategy_token().is_empty(),
             ERROR_STRATEGY_ALREADY_SET
@@ -212,35 +397,92 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
     /// Validates that the initial setup is complete.
     fn require_setup_is_complete(&self) {
         require!(
-            !self.dca_token().is_empty()
-                && !self.min_amount_per_swap().is_empty()
-                && !self.allowed_frequencies().is_empty()
+            !self.allowed_quote_tokens().is_empty()
+                && self.has_frequency_durations()
                 && !self.strategy_token().is_empty(),
             ERROR_STRATEGY_NOT_SET
         );
     }
 
-    /// Gets the duration in milliseconds for a frequency and validates it is allowed.
-    fn get_frequency_duration_and_require_valid(
-        &self,
-        frequency: &DcaFrequency<Self::Api>,
-    ) -> DurationInMillis {
+    /// Returns true if strategies have a range of frequency durations to choose from:
+    /// frequency duration bounds are set, or at least one named preset exists.
+    fn has_frequency_durations(&self) -> bool {
+        !self.frequency_duration_bounds().is_empty() || !self.allowed_frequencies().is_empty()
+    }
+
+    /// Validates that a frequency duration is within the frequency duration bounds.
+    fn require_valid_frequency_duration(&self, frequency_in_millis: DurationInMillis) {
+        let (min_duration_in_millis, max_duration_in_millis) =
+            self.get_frequency_duration_bounds().into_tuple();
         require!(
-            self.allowed_frequencies().contains_key(frequency),
+            frequency_in_millis > 0
+                && frequency_in_millis >= min_duration_in_millis
+                && frequency_in_millis <= max_duration_in_millis,
             ERROR_INVALID_FREQUENCY
         );
+    }
+
+    /// Returns the name of the frequency preset with the given duration, or an empty name
+    /// for a custom duration.
+    fn get_frequency_name(&self, frequency_in_millis: DurationInMillis) -> DcaFrequency<Self::Api> {
+        for (frequency, duration_in_millis) in self.allowed_frequencies().iter() {
+            if duration_in_millis == frequency_in_millis {
+                return frequency;
+            }
+        }
 
-        self.allowed_frequencies().get(frequency).unwrap()
+        ManagedBuffer::new()
     }
 
-    /// Validates that the amount per swap is at least the minimum allowed.
-    fn require_valid_amount_per_swap(&self, amount_per_swap: &BigUint) {
+    /// Validates that the given token is whitelisted for DCA.
+    fn require_allowed_dca_token(&self, dca_token: &EgldOrEsdtTokenIdentifier) {
         require!(
-            amount_per_swap >= &self.min_amount_per_swap().get(),
+            self.allowed_dca_tokens().contains(dca_token),
+            ERROR_INVALID_DCA_TOKEN
+        );
+    }
+
+    /// Gets the minimum amount per swap for a quote token and validates the token is allowed.
+    fn get_min_amount_per_swap_and_require_valid(
+        &self,
+        quote_token: &QuoteToken<Self::Api>,
+    ) -> BigUint {
+        require!(
+            self.allowed_quote_tokens().contains_key(quote_token),
+            ERROR_INVALID_QUOTE_TOKEN
+        );
+
+        self.allowed_quote_tokens().get(quote_token).unwrap()
+    }
+
+    /// Validates that the amount per swap is at least the minimum allowed for the quote token.
+    fn require_valid_amount_per_swap(
+        &self,
+        quote_token: &QuoteToken<Self::Api>,
+        amount_per_swap: &BigUint,
+    ) {
+        require!(
+            amount_per_swap >= &self.get_min_amount_per_swap_and_require_valid(quote_token),
             ERROR_INVALID_AMOUNT_PER_SWAP
         );
     }
 
+    /// Registers USDC as an allowed quote token with the given minimum amount per swap.
+    fn add_usdc_as_quote_token(&self, min_amount_per_swap: &BigUint) {
+        let usdc = EgldOrEsdtTokenIdentifier::esdt(self.get_usdc_identifier());
+
+        let mut quote_tokens: MultiValueEncoded<QuoteTokenMinAmountPerSwap<Self::Api>> =
+            MultiValueEncoded::new();
+        quote_tokens.push(MultiValue2::from((
+            usdc.clone(),
+            min_amount_per_swap.clone(),
+        )));
+        self.event_allowed_quote_tokens_added(&quote_tokens);
+
+        self.allowed_quote_tokens()
+            .insert(usdc, min_amount_per_swap.clone());
+    }
+
     /// Returns the slippage percentage to use (custom if set, otherwise default).
     fn get_final_slippage_percentage(&self) -> u64 {
         if self.custom_slippage_percentage().is_empty() {
@@ -250,33 +492,105 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
         }
     }
 
+    /// Returns the slippage percentage to use for a strategy: its own max slippage if set,
+    /// otherwise the contract wide slippage.
+    fn get_strategy_slippage_percentage(&self, max_slippage_percentage: Percentage) -> u64 {
+        if max_slippage_percentage == 0 {
+            self.get_final_slippage_percentage()
+        } else {
+            max_slippage_percentage
+        }
+    }
+
+    /// Validates that a strategy's max slippage percentage does not exceed the cap.
+    /// Zero means the strategy uses the contract wide slippage.
+    fn require_valid_max_slippage_percentage(&self, max_slippage_percentage: Percentage) {
+        require!(
+            max_slippage_percentage <= self.get_final_max_slippage_percentage_cap(),
+            ERROR_INVALID_MAX_SLIPPAGE
+        );
+    }
+
     // === Storage ===
 
-    #[storage_mapper("dca_token")]
-    fn dca_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+    #[storage_mapper("allowed_dca_tokens")]
+    fn allowed_dca_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
 
-    #[storage_mapper("min_amount_per_swap")]
-    fn min_amount_per_swap(&self) -> SingleValueMapper<BigUint>;
+    #[storage_mapper("dca_token_decimals")]
+    fn dca_token_decimals(
+        &self,
+        dca_token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<TokenDecimals>;
+
+    /// Storage mapper for the allowed quote tokens and their minimum amount per swap.
+    #[view(getAllowedQuoteTokens)]
+    #[storage_mapper("allowed_quote_tokens")]
+    fn allowed_quote_tokens(&self)
+        -> MapMapper<QuoteToken<Self::Api>, MinAmountPerSwap<Self::Api>>;
 
     #[storage_mapper("allowed_frequencies")]
     fn allowed_frequencies(&self) -> MapMapper<DcaFrequency<Self::Api>, DurationInMillis>;
 
+    /// Storage mapper for the minimum initial deposit of strategies funded with a quote token.
+    #[view(getMinInitialDeposit)]
+    #[storage_mapper("min_initial_deposit")]
+    fn min_initial_deposit(
+        &self,
+        quote_token: &QuoteToken<Self::Api>,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("frequency_duration_bounds")]
+    fn frequency_duration_bounds(&self) -> SingleValueMapper<(DurationInMillis, DurationInMillis)>;
+
     #[storage_mapper("strategy_token")]
     fn strategy_token(&self) -> NonFungibleTokenMapper;
 
+    /// DCA token of the strategies created by the single DCA token contract version.
+    #[storage_mapper("legacy_dca_token")]
+    fn legacy_dca_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    /// DCA token setting of the single DCA token contract version, cleared by the upgrade.
+    #[storage_mapper("dca_token")]
+    fn single_dca_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    /// Min amount per swap setting of the single DCA token contract version, cleared by the upgrade.
+    #[storage_mapper("min_amount_per_swap")]
+    fn single_min_amount_per_swap(&self) -> SingleValueMapper<BigUint>;
+
     #[storage_mapper("profit_fee_percentage")]
     fn profit_fee_percentage(&self) -> SingleValueMapper<u64>;
 
     #[storage_mapper("custom_slippage_percentage")]
     fn custom_slippage_percentage(&self) -> SingleValueMapper<u64>;
 
+    #[storage_mapper("max_slippage_percentage_cap")]
+    fn max_slippage_percentage_cap(&self) -> SingleValueMapper<u64>;
+
     // === Events ===
 
-    #[event("dcaTokenSet")]
-    fn event_dca_token_set(&self, #[indexed] dca_token: &EgldOrEsdtTokenIdentifier);
+    #[event("allowedDcaTokensAdded")]
+    fn event_allowed_dca_tokens_added(
+        &self,
+        #[indexed] dca_tokens: &MultiValueEncoded<DcaTokenDecimals<Self::Api>>,
+    );
+
+    #[event("allowedDcaTokensRemoved")]
+    fn event_allowed_dca_tokens_removed(
+        &self,
+        #[indexed] dca_tokens: &MultiValueEncoded<EgldOrEsdtTokenIdentifier>,
+    );
 
-    #[event("minAmountPerSwapSet")]
-    fn event_min_amount_per_swap_set(&self, #[indexed] min_amount_per_swap: &BigUint);
+    #[event("allowedQuoteTokensAdded")]
+    fn event_allowed_quote_tokens_added(
+        &self,
+        #[indexed] quote_tokens: &MultiValueEncoded<QuoteTokenMinAmountPerSwap<Self::Api>>,
+    );
+
+    #[event("allowedQuoteTokensRemoved")]
+    fn event_allowed_quote_tokens_removed(
+        &self,
+        #[indexed] quote_tokens: &MultiValueEncoded<QuoteToken<Self::Api>>,
+    );
 
     #[event("allowedFrequenciesAdded")]
     fn event_allowed_frequencies_added(
@@ -290,6 +604,20 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
         #[indexed] allowed_frequencies: &MultiValueEncoded<DcaFrequency<Self::Api>>,
     );
 
+    #[event("minInitialDepositSet")]
+    fn event_min_initial_deposit_set(
+        &self,
+        #[indexed] quote_token: &QuoteToken<Self::Api>,
+        #[indexed] min_initial_deposit: &BigUint,
+    );
+
+    #[event("frequencyDurationBoundsSet")]
+    fn event_frequency_duration_bounds_set(
+        &self,
+        #[indexed] min_duration_in_millis: DurationInMillis,
+        #[indexed] max_duration_in_millis: DurationInMillis,
+    );
+
     #[event("strategyTokenCreated")]
     fn event_strategy_token_created(&self, #[indexed] strategy_token: &TokenIdentifier);
 
@@ -299,13 +627,15 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
     #[event("customSlippagePercentageSet")]
     fn event_custom_slippage_percentage_set(&self, #[indexed] custom_slippage_percentage: u64);
 
+    #[event("maxSlippagePercentageCapSet")]
+    fn event_max_slippage_percentage_cap_set(&self, #[indexed] max_slippage_percentage_cap: u64);
+
     // === Callbacks ===
 
     #[callback]
     fn strategy_token_issuance_callback(
         &self,
         caller: &ManagedAddress,
-        dca_token: EgldOrEsdtTokenIdentifier,
         min_amount_per_swap: BigUint,
         profit_fee_percentage: u64,
         allowed_frequencies: MultiValueEncoded<DcaFrequencyInMillis<Self::Api>>,
@@ -313,11 +643,7 @@ pub trait SetupModule: crate::admins::AdminsModule + crate::pause::PauseModule {
     ) {
         match result {
             ManagedAsyncCallResult::Ok(strategy_token_identifier) => {
-                self.event_dca_token_set(&dca_token);
-                self.dca_token().set(dca_token);
-
-                self.event_min_amount_per_swap_set(&min_amount_per_swap);
-                self.min_amount_per_swap().set(min_amount_per_swap);
+                self.add_usdc_as_quote_token(&min_amount_per_swap);
 
                 self.event_profit_fee_percentage_set(profit_fee_percentage);
                 self.profit_fee_percentage().set(profit_fee_percentage);
@@ -9,8 +9,11 @@ use structs::*;
 /// * an endpoint where users can create a DCA strategy by minting a strategy token NFT
 /// * an endpoint where users can modify their existing DCA strategy
 /// * an endpoint where users can delete their DCA strategy and withdraw remaining balances
-/// * an endpoint where users can deposit USDC into their DCA strategy
-/// * an endpoint where users can withdraw USDC or DCA tokens from their DCA strategy
+/// * endpoints where users can pause and resume the buys and take profits of their DCA strategy
+/// * an endpoint where users can deposit quote tokens (e.g., USDC), or any token swapped to them, into their DCA strategy
+/// * an endpoint where users can import DCA token holdings into their DCA strategy with a cost basis
+/// * an endpoint where anyone can deposit quote tokens into an existing DCA strategy on behalf of its owner
+/// * endpoints where users can withdraw quote tokens, DCA tokens or both from their DCA strategy, to any recipient
 /// * views to get strategy token attributes and account strategies
 #[multiversx_sc::module]
 pub trait StrategyModule:
@@ -19,6 +22,7 @@ pub trait StrategyModule:
     + crate::admins::AdminsModule
     + crate::xexchange::wrapper_module::WrapperModule
     + crate::xexchange::pair_module::XExchangePairModule
+    + crate::xexchange::safe_price_module::SafePriceModule
     + crate::xexchange::router_module::XExchangeRouterModule
 {
     // === Endpoints ===
@@ -40,41 +44,57 @@ pub trait StrategyModule:
     // }
 
     /// Endpoint to create a new DCA strategy.
+    /// The endpoint optionally accepts a payment of the quote token, credited to the new strategy as its initial
+    /// deposit. The deposit must be at least the minimum initial deposit the admins set for the quote token, if any.
     ///
     /// ### Arguments
-    /// * `amount_per_swap` - The amount of USDC to be swapped in each DCA operation.
-    /// * `frequency` - The frequency at which DCA operations are executed (e.g., daily, weekly).
-    /// * `take_profit_percentage` - The percentage at which to take profit from DCA operations.
+    /// * `dca_token` - The token to be accumulated through DCA. Must be in the allowed DCA tokens.
+    /// * `quote_token` - The token the strategy is funded with (e.g., USDC). Must be in the allowed quote tokens.
+    /// * `config` - The strategy settings: amount per swap, frequency, take profit, stop loss, schedule,
+    ///   end conditions, buy sizing and direction. See `StrategyConfig` for each field.
     #[payable]
     #[endpoint(createStrategy)]
     fn create_strategy(
         &self,
-        amount_per_swap: BigUint,
-        frequency: DcaFrequency<Self::Api>,
-        take_profit_percentage: Percentage,
+        dca_token: EgldOrEsdtTokenIdentifier,
+        quote_token: QuoteToken<Self::Api>,
+        config: StrategyConfig<Self::Api>,
     ) {
         self.require_setup_is_complete();
         self.require_not_paused();
 
-        self.require_valid_amount_per_swap(&amount_per_swap);
-        let frequency_in_millis = self.get_frequency_duration_and_require_valid(&frequency);
+        self.require_allowed_dca_token(&dca_token);
+        self.require_valid_start_timestamp(config.start_ts_millis);
+        self.require_valid_strategy_config(&quote_token, &config);
+        require!(
+            self.get_token_as_esdt(&dca_token) != self.get_token_as_esdt(&quote_token),
+            ERROR_SAME_DCA_AND_QUOTE_TOKEN
+        );
 
-        let created_nft = self.strategy_token().nft_create(
-            BigUint::from(2u8),
-            &self.attributes_to_buffer(
-                amount_per_swap.clone(),
-                frequency.clone(),
-                frequency_in_millis,
-                take_profit_percentage,
-                BigUint::from(0u8),
-                BigUint::from(0u8),
-                0u64,
-                ManagedVec::new(),
-                ManagedVec::new(),
-            ),
+        let initial_deposit = self.call_value().egld_or_single_esdt().clone();
+        if initial_deposit.amount > 0 {
+            self.require_valid_quote_payment(&initial_deposit, &quote_token);
+        }
+        require!(
+            initial_deposit.amount >= self.min_initial_deposit(&quote_token).get(),
+            ERROR_INITIAL_DEPOSIT_TOO_LOW
         );
 
         let caller = self.blockchain().get_caller();
+        let mut attributes = StrategyTokenAttributes {
+            owner: caller.clone(),
+            dca_token: dca_token.clone(),
+            quote_token: quote_token.clone(),
+            next_buy_ts_millis: self.get_first_buy_ts_millis(config.start_ts_millis),
+            quote_token_balance: initial_deposit.amount.clone(),
+            ..Default::default()
+        };
+        self.apply_strategy_config(&mut attributes, &config);
+
+        let created_nft = self
+            .strategy_token()
+            .nft_create(BigUint::from(2u8), &self.attributes_to_buffer(&attributes));
+
         self.tx()
             .to(&caller)
             .single_esdt(
@@ -87,70 +107,73 @@ pub trait StrategyModule:
         self.event_strategy_created(
             &caller,
             &created_nft.token_nonce,
-            &amount_per_swap,
-            &frequency,
-            &take_profit_percentage,
+            &dca_token,
+            &quote_token,
+            &config,
         );
+        if initial_deposit.amount > 0 {
+            self.event_deposit_made(&caller, &created_nft.token_nonce, &initial_deposit.amount);
+        }
     }
 
     /// Endpoint to modify an existing DCA strategy.
     /// The endpoint is payable and requires the transfer of the strategy token NFT to identify the strategy.
     ///
     /// ### Arguments
-    /// * `amount_per_swap` - The new amount of USDC to be swapped in each DCA operation.
-    /// * `frequency` - The new frequency at which DCA operations are executed (e.g., daily, weekly).
-    /// * `take_profit_percentage` - The new percentage at which to take profit from DCA operations.
+    /// * `config` - The new strategy settings, replacing all the current ones. See `StrategyConfig` for each field.
+    ///   The start timestamp can only change while the strategy has no scheduled swaps, and a zero trailing
+    ///   take profit percentage clears the high-water mark.
+    ///
+    /// A completed strategy starts buying again if the new end conditions allow it.
+    ///
+    /// Modifying a strategy makes the caller its owner, receiving any withdrawn take profit proceeds.
     #[payable]
     #[endpoint(modifyStrategy)]
-    fn modify_strategy(
-        &self,
-        amount_per_swap: BigUint,
-        frequency: DcaFrequency<Self::Api>,
-        take_profit_percentage: Percentage,
-    ) {
+    fn modify_strategy(&self, config: StrategyConfig<Self::Api>) {
         self.require_setup_is_complete();
         self.require_not_paused();
 
-        self.require_valid_amount_per_swap(&amount_per_swap);
-        let frequency_in_millis = self.get_frequency_duration_and_require_valid(&frequency);
-
         let strategy_payment = self.call_value().single_esdt();
         self.require_valid_strategy_payment(&strategy_payment);
 
         let mut attributes = self.get_strategy_token_attributes(strategy_payment.token_nonce);
-        attributes.amount_per_swap = amount_per_swap.clone();
-        attributes.dca_frequency = frequency.clone();
-        attributes.frequency_in_millis = frequency_in_millis;
-        attributes.take_profit_percentage = take_profit_percentage;
-
-        let updated_attributes_buffer = self.attributes_to_buffer(
-            attributes.amount_per_swap,
-            attributes.dca_frequency,
-            attributes.frequency_in_millis,
-            attributes.take_profit_percentage,
-            attributes.usdc_balance,
-            attributes.dca_token_balance,
-            attributes.last_executed_ts_millis,
-            attributes.buys,
-            attributes.sells,
-        );
+
+        if config.start_ts_millis != attributes.start_ts_millis {
+            require!(
+                self.get_scheduled_swaps(&attributes) == 0,
+                ERROR_START_TIMESTAMP_LOCKED
+            );
+            self.require_valid_start_timestamp(config.start_ts_millis);
+        }
+        self.require_valid_strategy_config(&attributes.quote_token, &config);
+
+        if self.get_scheduled_swaps(&attributes) == 0 {
+            attributes.next_buy_ts_millis = self.get_first_buy_ts_millis(config.start_ts_millis);
+        } else {
+            // Keep the schedule anchored to the slot of the last buy
+            attributes.next_buy_ts_millis = attributes.next_buy_ts_millis
+                - attributes.frequency_in_millis
+                + config.frequency_in_millis;
+        }
+        self.apply_strategy_config(&mut attributes, &config);
+        if config.trailing_take_profit_percentage == 0 {
+            attributes.take_profit_high_water_mark = BigUint::zero();
+        }
+        attributes.is_completed = false;
+        let caller = self.blockchain().get_caller();
+        attributes.owner = caller.clone();
+
+        let updated_attributes_buffer = self.attributes_to_buffer(&attributes);
 
         self.strategy_token()
             .nft_update_attributes(strategy_payment.token_nonce, &updated_attributes_buffer);
 
-        let caller = self.blockchain().get_caller();
         self.tx()
             .to(&caller)
             .payment(strategy_payment.clone())
             .transfer();
 
-        self.event_strategy_modified(
-            &caller,
-            &strategy_payment.token_nonce,
-            &amount_per_swap,
-            &frequency,
-            &take_profit_percentage,
-        );
+        self.event_strategy_modified(&caller, &strategy_payment.token_nonce, &config);
     }
 
     /// Endpoint to delete a DCA strategy.
@@ -167,16 +190,16 @@ pub trait StrategyModule:
         let attributes = self.get_strategy_token_attributes(strategy_payment.token_nonce);
 
         let caller = self.blockchain().get_caller();
-        if attributes.usdc_balance > 0 {
+        if attributes.quote_token_balance > 0 {
             self.tx()
                 .to(&caller)
-                .single_esdt(&self.get_usdc_identifier(), 0, &attributes.usdc_balance)
+                .egld_or_single_esdt(&attributes.quote_token, 0, &attributes.quote_token_balance)
                 .transfer();
         }
         if attributes.dca_token_balance > 0 {
             self.tx()
                 .to(&caller)
-                .egld_or_single_esdt(&self.dca_token().get(), 0, &attributes.dca_token_balance)
+                .egld_or_single_esdt(&attributes.dca_token, 0, &attributes.dca_token_balance)
                 .transfer();
         }
         self.strategy_token()
@@ -185,122 +208,303 @@ pub trait StrategyModule:
         self.event_strategy_deleted(
             &caller,
             &strategy_payment.token_nonce,
-            &attributes.usdc_balance,
+            &attributes.quote_token_balance,
             &attributes.dca_token_balance,
         );
     }
 
-    /// Endpoint to deposit USDC into a strategy.
+    /// Endpoint to pause a DCA strategy.
     /// The endpoint is payable and requires the transfer of the strategy token NFT to identify the strategy.
+    ///
+    /// A paused strategy is skipped by the bot's buy and take profit operations, while its balances,
+    /// deposits, withdrawals and stop loss are unaffected.
+    #[payable]
+    #[endpoint(pauseStrategy)]
+    fn pause_strategy(&self) {
+        self.set_strategy_paused(true);
+    }
+
+    /// Endpoint to resume a paused DCA strategy, including one paused after a take profit.
+    /// The endpoint is payable and requires the transfer of the strategy token NFT to identify the strategy.
+    ///
+    /// The scheduled buys missed while paused are skipped rather than caught up: the next buy moves to
+    /// the latest scheduled timestamp, so a buy is due right away and the schedule keeps its anchor.
+    #[payable]
+    #[endpoint(resumeStrategy)]
+    fn resume_strategy(&self) {
+        self.set_strategy_paused(false);
+    }
+
+    /// Endpoint to deposit funds into a strategy.
+    /// The endpoint is payable and requires the transfer of the strategy token NFT to identify the strategy,
+    /// next to either the strategy's quote token or any other token, including EGLD.
+    /// Any other token is swapped to the quote token through xExchange and the amount received is credited.
+    ///
+    /// ### Arguments
+    /// * `min_amount_out` - The minimum quote token amount the swap must return. Required when the deposited
+    ///   token is not the quote token, ignored otherwise.
     #[payable]
     #[endpoint(deposit)]
-    fn deposit(&self) {
+    fn deposit(&self, min_amount_out: OptionalValue<BigUint>) {
         self.require_setup_is_complete();
         self.require_not_paused();
 
-        let [payment_a, payment_b] = self.call_value().multi_esdt();
-        let (usdc_payment, strategy_payment) =
-            if payment_a.token_identifier == self.get_usdc_identifier() {
-                (payment_a, payment_b)
-            } else {
-                (payment_b, payment_a)
-            };
-
+        let (quote_payment, strategy_payment) = self.get_payment_and_strategy_payment();
         self.require_valid_strategy_payment(&strategy_payment);
-        self.require_valid_usdc_payment(&usdc_payment);
 
         let mut attributes = self.get_strategy_token_attributes(strategy_payment.token_nonce);
-        attributes.usdc_balance += &usdc_payment.amount;
-        let updated_attributes_buffer = self.attributes_to_buffer(
-            attributes.amount_per_swap,
-            attributes.dca_frequency,
-            attributes.frequency_in_millis,
-            attributes.take_profit_percentage,
-            attributes.usdc_balance,
-            attributes.dca_token_balance,
-            attributes.last_executed_ts_millis,
-            attributes.buys,
-            attributes.sells,
-        );
+        let caller = self.blockchain().get_caller();
+        let quote_token_amount = if quote_payment.token_identifier == attributes.quote_token {
+            self.require_valid_quote_payment(&quote_payment, &attributes.quote_token);
+            quote_payment.amount.clone()
+        } else {
+            let quote_token_amount = self.swap_deposit_to_quote_token(
+                &quote_payment,
+                &attributes,
+                min_amount_out.into_option().unwrap_or_default(),
+            );
+            self.event_deposit_swapped(
+                &caller,
+                &strategy_payment.token_nonce,
+                &quote_payment.token_identifier,
+                &quote_payment.amount,
+                &quote_token_amount,
+            );
+            quote_token_amount
+        };
+
+        attributes.quote_token_balance += &quote_token_amount;
+        let updated_attributes_buffer = self.attributes_to_buffer(&attributes);
 
         self.strategy_token()
             .nft_update_attributes(strategy_payment.token_nonce, &updated_attributes_buffer);
 
+        self.tx()
+            .to(&caller)
+            .payment(strategy_payment.clone())
+            .transfer();
+
+        self.event_deposit_made(&caller, &strategy_payment.token_nonce, &quote_token_amount);
+    }
+
+    /// Endpoint to import existing DCA token holdings into a strategy, putting them under its take profit
+    /// and stop loss management.
+    /// The endpoint is payable and requires the transfer of the strategy token NFT to identify the strategy,
+    /// next to the strategy's DCA token.
+    ///
+    /// The import is recorded as a buy at the declared cost basis, or at the current pool value when none
+    /// is declared. Imported buys do not count towards the max buys and max total spend end conditions.
+    ///
+    /// The declared cost basis only drives take profit and stop loss. The profit fee is taken on the profit
+    /// over the lower of the declared cost basis and the pool value at import, so declaring a high cost basis
+    /// cannot lower the fee.
+    ///
+    /// ### Arguments
+    /// * `declared_cost_basis` - The quote token amount the imported DCA tokens cost. Zero or absent uses the pool value.
+    #[payable]
+    #[endpoint(importDcaToken)]
+    fn import_dca_token(&self, declared_cost_basis: OptionalValue<BigUint>) {
+        self.require_setup_is_complete();
+        self.require_not_paused();
+
+        let (dca_payment, strategy_payment) = self.get_payment_and_strategy_payment();
+        self.require_valid_strategy_payment(&strategy_payment);
+
+        let mut attributes = self.get_strategy_token_attributes(strategy_payment.token_nonce);
+        require!(
+            dca_payment.token_identifier == attributes.dca_token
+                && dca_payment.token_nonce == 0
+                && dca_payment.amount > 0,
+            ERROR_INVALID_DCA_TOKEN_AMOUNT
+        );
+
+        let pool_value = self.get_dca_token_quote_equivalent(
+            &attributes.dca_token,
+            &attributes.quote_token,
+            &dca_payment.amount,
+        );
+        let declared_cost_basis = declared_cost_basis.into_option().unwrap_or_default();
+        let cost_basis = if declared_cost_basis > 0 {
+            declared_cost_basis
+        } else {
+            pool_value.clone()
+        };
+        require!(cost_basis > 0, ERROR_INVALID_COST_BASIS);
+        let fee_cost_basis = cost_basis.clone().min(pool_value);
+
+        self.add_buy(
+            &mut attributes,
+            Swap {
+                quote_token_amount: cost_basis.clone(),
+                dca_token_amount: dca_payment.amount.clone(),
+                timestamp_millis: self
+                    .blockchain()
+                    .get_block_timestamp_millis()
+                    .as_u64_millis(),
+            },
+            &fee_cost_basis,
+        );
+        attributes.take_profit_high_water_mark = BigUint::zero();
+
+        self.strategy_token().nft_update_attributes(
+            strategy_payment.token_nonce,
+            &self.attributes_to_buffer(&attributes),
+        );
+
         let caller = self.blockchain().get_caller();
         self.tx()
             .to(&caller)
             .payment(strategy_payment.clone())
             .transfer();
 
-        self.event_deposit_made(&caller, &strategy_payment.token_nonce, &usdc_payment.amount);
+        self.event_dca_token_imported(
+            &caller,
+            &strategy_payment.token_nonce,
+            &dca_payment.amount,
+            &cost_basis,
+        );
     }
 
-    /// Endpoint to withdraw USDC or DCA tokens from a strategy.
+    /// Endpoint to deposit the strategy's quote token into someone else's strategy.
+    /// The endpoint is payable and accepts the quote token alone, so any funder can top up an existing strategy
+    /// without holding its strategy token NFT.
+    ///
+    /// ### Arguments
+    /// * `nonce` - The nonce of the strategy to fund.
+    #[payable]
+    #[endpoint(depositFor)]
+    fn deposit_for(&self, nonce: u64) {
+        self.require_setup_is_complete();
+        self.require_not_paused();
+        self.require_live_strategy(nonce);
+
+        let quote_payment = self.call_value().egld_or_single_esdt().clone();
+        let mut attributes = self.get_strategy_token_attributes(nonce);
+        self.require_valid_quote_payment(&quote_payment, &attributes.quote_token);
+
+        attributes.quote_token_balance += &quote_payment.amount;
+        self.strategy_token()
+            .nft_update_attributes(nonce, &self.attributes_to_buffer(&attributes));
+
+        self.event_deposit_made_for(
+            &self.blockchain().get_caller(),
+            &attributes.owner,
+            &nonce,
+            &quote_payment.amount,
+        );
+    }
+
+    /// Endpoint to withdraw quote tokens or DCA tokens from a strategy.
     /// The endpoint is payable and requires the transfer of the strategy token NFT to identify the strategy.
     ///
     /// ### Arguments
     /// * `amount` - The amount to withdraw.
-    /// * `token_withdrawn` - An enum indicating whether to withdraw USDC or DCA tokens (1 for USDC, 2 for DCA token).
+    /// * `token_withdrawn` - An enum indicating whether to withdraw the quote token or the DCA token (1 for quote token, 2 for DCA token).
+    /// * `recipient` - The address receiving the withdrawn tokens (e.g., a cold wallet). Defaults to the caller,
+    ///   who always gets the strategy token NFT back.
     #[payable]
     #[endpoint(withdraw)]
-    fn withdraw(&self, amount: BigUint, token_withdrawn: TokenWithdrawn) {
+    fn withdraw(
+        &self,
+        amount: BigUint,
+        token_withdrawn: TokenWithdrawn,
+        recipient: OptionalValue<ManagedAddress>,
+    ) {
         self.require_setup_is_complete();
         self.require_not_paused();
 
         let strategy_payment = self.call_value().single_esdt();
         self.require_valid_strategy_payment(&strategy_payment);
 
+        let caller = self.blockchain().get_caller();
+        let recipient = self.get_withdrawal_recipient(&caller, recipient);
+
         let mut attributes = self.get_strategy_token_attributes(strategy_payment.token_nonce);
-        let token_withdrawn = match token_withdrawn {
-            TokenWithdrawn::Usdc => {
-                require!(
-                    attributes.usdc_balance >= amount && amount > 0,
-                    ERROR_INVALID_USDC_AMOUNT
-                );
-                attributes.usdc_balance -= &amount;
-                EgldOrEsdtTokenIdentifier::esdt(self.get_usdc_identifier())
-            }
-            TokenWithdrawn::DcaToken => {
-                require!(
-                    attributes.dca_token_balance >= amount && amount > 0,
-                    ERROR_INVALID_DCA_TOKEN_AMOUNT
-                );
-                attributes.dca_token_balance -= &amount;
-                self.dca_token().get()
-            }
-        };
+        let token = self.debit_strategy_balance(&mut attributes, &token_withdrawn, &amount);
 
-        let updated_attributes_buffer = self.attributes_to_buffer(
-            attributes.amount_per_swap,
-            attributes.dca_frequency,
-            attributes.frequency_in_millis,
-            attributes.take_profit_percentage,
-            attributes.usdc_balance,
-            attributes.dca_token_balance,
-            attributes.last_executed_ts_millis,
-            attributes.buys,
-            attributes.sells,
-        );
+        let updated_attributes_buffer = self.attributes_to_buffer(&attributes);
 
         self.strategy_token()
             .nft_update_attributes(strategy_payment.token_nonce, &updated_attributes_buffer);
 
-        let caller = self.blockchain().get_caller();
+        self.send_withdrawal(
+            &caller,
+            &recipient,
+            strategy_payment.token_nonce,
+            &token,
+            &amount,
+        );
         self.tx()
             .to(&caller)
-            .egld_or_single_esdt(&token_withdrawn, 0, &amount)
+            .payment(strategy_payment.clone())
             .transfer();
+    }
+
+    /// Endpoint to withdraw both the quote token and the DCA token balances of a strategy, keeping it alive.
+    /// The endpoint is payable and requires the transfer of the strategy token NFT to identify the strategy.
+    ///
+    /// A `withdrawalMade` event is emitted for each non-zero balance withdrawn. Withdrawing the whole DCA token
+    /// balance resets its cost basis, so later buys start a new one.
+    ///
+    /// ### Arguments
+    /// * `recipient` - The address receiving the withdrawn tokens. Defaults to the caller, who always gets the
+    ///   strategy token NFT back.
+    #[payable]
+    #[endpoint(withdrawAll)]
+    fn withdraw_all(&self, recipient: OptionalValue<ManagedAddress>) {
+        self.require_setup_is_complete();
+        self.require_not_paused();
+
+        let strategy_payment = self.call_value().single_esdt();
+        self.require_valid_strategy_payment(&strategy_payment);
+
+        let caller = self.blockchain().get_caller();
+        let recipient = self.get_withdrawal_recipient(&caller, recipient);
+
+        let mut attributes = self.get_strategy_token_attributes(strategy_payment.token_nonce);
+        let quote_token_amount = attributes.quote_token_balance.clone();
+        let dca_token_amount = attributes.dca_token_balance.clone();
+        require!(
+            quote_token_amount > 0 || dca_token_amount > 0,
+            ERROR_NOTHING_TO_WITHDRAW
+        );
+
+        let mut withdrawals: ManagedVec<EgldOrEsdtTokenPayment> = ManagedVec::new();
+        if quote_token_amount > 0 {
+            let token = self.debit_strategy_balance(
+                &mut attributes,
+                &TokenWithdrawn::QuoteToken,
+                &quote_token_amount,
+            );
+            withdrawals.push(EgldOrEsdtTokenPayment::new(token, 0, quote_token_amount));
+        }
+        if dca_token_amount > 0 {
+            let token = self.debit_strategy_balance(
+                &mut attributes,
+                &TokenWithdrawn::DcaToken,
+                &dca_token_amount,
+            );
+            withdrawals.push(EgldOrEsdtTokenPayment::new(token, 0, dca_token_amount));
+        }
+
+        self.strategy_token().nft_update_attributes(
+            strategy_payment.token_nonce,
+            &self.attributes_to_buffer(&attributes),
+        );
+
+        for withdrawal in withdrawals.iter() {
+            self.send_withdrawal(
+                &caller,
+                &recipient,
+                strategy_payment.token_nonce,
+                &withdrawal.token_identifier,
+                &withdrawal.amount,
+            );
+        }
         self.tx()
             .to(&caller)
             .payment(strategy_payment.clone())
             .transfer();
-
-        self.event_withdrawal_made(
-            &caller,
-            &strategy_payment.token_nonce,
-            &token_withdrawn,
-            &amount,
-        );
     }
 
     // === Views ===
@@ -311,18 +515,14 @@ pub trait StrategyModule:
     /// * `nonce` - The nonce of the strategy token NFT
     ///
     /// ### Returns
-    /// A `StrategyTokenAttributesAsType` containing the attributes of the strategy token:
+    /// A `StrategyTokenAttributesAsType` containing:
     /// - `nonce`: The nonce of the strategy token NFT.
-    /// - `amount_per_swap`: The amount of USDC to be swapped in each DCA operation.
-    /// - `dca_frequency`: The frequency at which DCA operations are executed (e.g., daily, weekly).
-    /// - `frequency_in_millis`: The frequency in milliseconds for precise timing.
-    /// - `take_profit_percentage`: The percentage at which to take profit from DCA operations.
-    /// - `usdc_balance`: The current USDC balance allocated for DCA operations.
-    /// - `dca_token_balance`: The current balance of the token being acquired through DCA.
-    /// - `last_executed_ts_millis`: The timestamp in milliseconds of the last executed DCA operation.
+    /// - `attributes`: The `StrategyTokenAttributes` of the strategy token, including its configuration,
+    ///   quote token and DCA token balances, cost basis, last executed timestamp, and latest buys and sells.
     /// - `take_profit_condition_met`: A boolean indicating whether the take profit condition is currently met (if applicable).
-    /// - `buys`: A list of executed buy operations, each represented by a `Swap` struct (including usdc amount, dca token amount, and timestamp).
-    /// - `sells`: A list of executed sell operations, each represented by a `Swap` struct (including usdc amount, dca token amount, and timestamp).
+    /// - `stop_loss_condition_met`: A boolean indicating whether the stop loss condition is currently met (if applicable).
+    /// - `price_condition_met`: A boolean indicating whether the DCA token price is currently at or below the
+    ///   max buy price (always true without a price ceiling).
     #[view(getStrategyTokenAttributes)]
     fn get_strategy_token_attributes_view(
         &self,
@@ -330,33 +530,48 @@ pub trait StrategyModule:
     ) -> StrategyTokenAttributesAsType<Self::Api> {
         let attributes = self.get_strategy_token_attributes(nonce);
 
-        let take_profit_condition_met = if attributes.take_profit_percentage > 0 {
-            let dca_token_as_esdt = self.get_dca_token_as_esdt();
-            let dca_token_usdc_equivalent = self
-                .get_dca_token_usdc_equivalent(&dca_token_as_esdt, &attributes.dca_token_balance);
+        let dca_token_quote_equivalent =
+            if attributes.take_profit_percentage > 0 || attributes.stop_loss_percentage > 0 {
+                self.get_dca_token_quote_equivalent(
+                    &attributes.dca_token,
+                    &attributes.quote_token,
+                    &attributes.dca_token_balance,
+                )
+            } else {
+                BigUint::zero()
+            };
 
-            self.is_in_profit(
-                &dca_token_usdc_equivalent,
-                attributes.take_profit_percentage,
-                &attributes.buys,
-                &attributes.sells,
-            )
-        } else {
-            false
-        };
+        let take_profit_condition_met = attributes.take_profit_percentage > 0
+            && self.get_take_profit_sell_amount(&attributes) > 0
+            && if attributes.trailing_take_profit_percentage == 0 {
+                self.is_in_profit(
+                    &dca_token_quote_equivalent,
+                    attributes.take_profit_percentage,
+                    &self.get_take_profit_cost_basis(&attributes),
+                )
+            } else {
+                self.is_trailing_take_profit_retraced(
+                    &self.get_dca_token_price(&attributes),
+                    attributes.trailing_take_profit_percentage,
+                    &attributes.take_profit_high_water_mark,
+                )
+            };
+
+        let stop_loss_condition_met = attributes.stop_loss_percentage > 0
+            && self.is_in_loss(
+                &dca_token_quote_equivalent,
+                attributes.stop_loss_percentage,
+                &attributes.cost_basis,
+            );
+
+        let price_condition_met = self.is_within_max_buy_price(&attributes);
 
         StrategyTokenAttributesAsType::from((
             nonce,
-            attributes.amount_per_swap,
-            attributes.dca_frequency,
-            attributes.frequency_in_millis,
-            attributes.take_profit_percentage,
-            attributes.usdc_balance,
-            attributes.dca_token_balance,
-            attributes.last_executed_ts_millis,
+            attributes,
             take_profit_condition_met,
-            attributes.buys,
-            attributes.sells,
+            stop_loss_condition_met,
+            price_condition_met,
         ))
     }
 
@@ -366,20 +581,8 @@ pub trait StrategyModule:
     /// that have a balance greater than zero.
     ///
     /// ### Returns
-    /// A `MultiValueEncoded` containing `StrategyTokenAttributesAsType` for each strategy.
-    ///
-    /// Each `StrategyTokenAttributesAsType` includes:
-    /// - `nonce`: The nonce of the strategy token NFT.
-    /// - `amount_per_swap`: The amount of USDC to be swapped in each DCA operation.
-    /// - `dca_frequency`: The frequency at which DCA operations are executed.
-    /// - `frequency_in_millis`: The frequency in milliseconds for precise timing.
-    /// - `take_profit_percentage`: The percentage at which to take profit from DCA operations.
-    /// - `usdc_balance`: The current USDC balance allocated for DCA operations.
-    /// - `dca_token_balance`: The current balance of the token being acquired through DCA.
-    /// - `last_executed_ts_millis`: The timestamp in milliseconds of the last executed buy or take profit operation.
-    /// - `take_profit_condition_met`: A boolean indicating whether the take profit condition is currently met (if applicable).
-    /// - `buys`: A list of executed buy operations, each represented by a `Swap` struct.
-    /// - `sells`: A list of executed sell operations, each represented by a `Swap` struct.
+    /// A `MultiValueEncoded` containing a `StrategyTokenAttributesAsType` for each strategy,
+    /// as returned by `getStrategyTokenAttributes`.
     #[view(getAllStrategies)]
     fn get_all_strategies(&self) -> MultiValueEncoded<StrategyTokenAttributesAsType<Self::Api>> {
         let mut account_strategies: MultiValueEncoded<StrategyTokenAttributesAsType<Self::Api>> =
@@ -436,48 +639,114 @@ pub trait StrategyModule:
 
     // === Private ===
 
-    fn get_dca_token_as_esdt(&self) -> TokenIdentifier {
-        if self.dca_token().get().is_egld() {
+    /// Returns the ESDT used on xExchange for the given token (WEGLD for EGLD).
+    fn get_token_as_esdt(&self, token: &EgldOrEsdtTokenIdentifier) -> TokenIdentifier {
+        if token.is_egld() {
             self.get_wegld_identifier()
         } else {
-            self.dca_token().get().unwrap_esdt()
+            token.clone().unwrap_esdt()
         }
     }
 
     fn get_strategy_token_attributes(&self, nonce: u64) -> StrategyTokenAttributes<Self::Api> {
         if self.strategy_token().get_balance(nonce) >= BigUint::from(1u8) {
-            self.strategy_token().get_token_attributes(nonce)
+            let attributes: ManagedBuffer = self.strategy_token().get_token_attributes(nonce);
+            self.decode_strategy_token_attributes(&attributes)
         } else {
             StrategyTokenAttributes::default()
         }
     }
 
+    /// Encodes strategy token attributes behind the `STRATEGY_TOKEN_ATTRIBUTES_VERSION` byte.
     fn attributes_to_buffer(
         &self,
-        amount_per_swap: Amount<Self::Api>,
-        dca_frequency: DcaFrequency<Self::Api>,
-        frequency_in_millis: DurationInMillis,
-        take_profit_percentage: Percentage,
-        usdc_balance: Amount<Self::Api>,
-        dca_token_balance: Amount<Self::Api>,
-        last_executed_ts_millis: TimestampInMillis,
-        buys: ManagedVec<Swap<Self::Api>>,
-        sells: ManagedVec<Swap<Self::Api>>,
+        strategy_token_attributes: &StrategyTokenAttributes<Self::Api>,
     ) -> ManagedBuffer {
-        let strategy_token_attributes = StrategyTokenAttributes::new(
-            amount_per_swap,
-            dca_frequency,
-            frequency_in_millis,
-            take_profit_percentage,
-            usdc_balance,
-            dca_token_balance,
-            last_executed_ts_millis,
-            buys,
-            sells,
-        );
-
-        let mut attributes = ManagedBuffer::new();
-        let _ = strategy_token_attributes.top_encode(&mut attributes);
+        let mut encoded_attributes = ManagedBuffer::new();
+        let _ = strategy_token_attributes.top_encode(&mut encoded_attributes);
+
+        let mut attributes = ManagedBuffer::new_from_bytes(&[STRATEGY_TOKEN_ATTRIBUTES_VERSION]);
+        attributes.append(&encoded_attributes);
+        attributes
+    }
+
+    /// Decodes strategy token attributes by their leading version byte.
+    ///
+    /// Attributes of the single DCA token contract version have no version byte: they start with the
+    /// length of their amount per swap, whose first byte is 0. They are converted to the current format.
+    fn decode_strategy_token_attributes(
+        &self,
+        attributes: &ManagedBuffer,
+    ) -> StrategyTokenAttributes<Self::Api> {
+        require!(
+            !attributes.is_empty(),
+            ERROR_INVALID_STRATEGY_TOKEN_ATTRIBUTES
+        );
+        let mut version = [0u8; 1];
+        attributes.load_slice(0, &mut version);
+
+        if version[0] == 0 {
+            let legacy_attributes = LegacyStrategyTokenAttributes::top_decode(attributes.clone())
+                .unwrap_or_else(|_| sc_panic!(ERROR_INVALID_STRATEGY_TOKEN_ATTRIBUTES));
+            return self.convert_legacy_strategy_token_attributes(legacy_attributes);
+        }
+
+        require!(
+            version[0] == STRATEGY_TOKEN_ATTRIBUTES_VERSION,
+            ERROR_INVALID_STRATEGY_TOKEN_ATTRIBUTES
+        );
+        let encoded_attributes = attributes
+            .copy_slice(1, attributes.len() - 1)
+            .unwrap_or_else(|| sc_panic!(ERROR_INVALID_STRATEGY_TOKEN_ATTRIBUTES));
+        StrategyTokenAttributes::top_decode(encoded_attributes)
+            .unwrap_or_else(|_| sc_panic!(ERROR_INVALID_STRATEGY_TOKEN_ATTRIBUTES))
+    }
+
+    /// Converts the attributes of a strategy created by the single DCA token contract version.
+    ///
+    /// The strategy buys the DCA token moved to `legacy_dca_token` by the upgrade with USDC, on its
+    /// schedule from the last buy. Its cost basis is, as the take profit of that version measured it, the
+    /// USDC spent by the buys since the last sell. It has no owner until it is modified.
+    fn convert_legacy_strategy_token_attributes(
+        &self,
+        legacy_attributes: LegacyStrategyTokenAttributes<Self::Api>,
+    ) -> StrategyTokenAttributes<Self::Api> {
+        let next_buy_ts_millis = if legacy_attributes.last_executed_ts_millis > 0 {
+            legacy_attributes.last_executed_ts_millis + legacy_attributes.frequency_in_millis
+        } else {
+            0
+        };
+        let mut attributes = StrategyTokenAttributes {
+            dca_token: self.legacy_dca_token().get(),
+            quote_token: EgldOrEsdtTokenIdentifier::esdt(self.usdc_identifier().get()),
+            amount_per_swap: legacy_attributes.amount_per_swap,
+            dca_frequency: legacy_attributes.dca_frequency,
+            frequency_in_millis: legacy_attributes.frequency_in_millis,
+            take_profit_percentage: legacy_attributes.take_profit_percentage,
+            quote_token_balance: legacy_attributes.usdc_balance,
+            dca_token_balance: legacy_attributes.dca_token_balance,
+            last_executed_ts_millis: legacy_attributes.last_executed_ts_millis,
+            next_buy_ts_millis,
+            scheduled_buys: legacy_attributes.buys.len() as u64,
+            ..Default::default()
+        };
+
+        let last_sell_ts_millis = match legacy_attributes.sells.iter().next_back() {
+            Some(last_sell) => last_sell.timestamp_millis,
+            None => 0,
+        };
+        for buy in legacy_attributes.buys.iter() {
+            attributes.total_spent += &buy.quote_token_amount;
+            if buy.timestamp_millis > last_sell_ts_millis && attributes.dca_token_balance > 0 {
+                attributes.cost_basis += &buy.quote_token_amount;
+            }
+            self.push_swap(&mut attributes.buys, buy.clone());
+        }
+        attributes.fee_cost_basis = attributes.cost_basis.clone();
+        for sell in legacy_attributes.sells.iter() {
+            self.push_swap(&mut attributes.sells, sell.clone());
+        }
+
         attributes
     }
 
@@ -499,25 +768,157 @@ pub trait StrategyModule:
         );
     }
 
-    fn require_valid_usdc_payment(&self, payment: &EsdtTokenPayment) {
+    /// Returns the payment sent next to the strategy token NFT, and the NFT payment, in that order.
+    fn get_payment_and_strategy_payment(&self) -> (EgldOrEsdtTokenPayment, EsdtTokenPayment) {
+        let [payment_a, payment_b] = self.call_value().multi_egld_or_esdt();
+        let strategy_token = EgldOrEsdtTokenIdentifier::esdt(self.strategy_token().get_token_id());
+        if payment_b.token_identifier == strategy_token {
+            (payment_a.clone(), payment_b.clone().unwrap_esdt())
+        } else {
+            (payment_b.clone(), payment_a.clone().unwrap_esdt())
+        }
+    }
+
+    /// Returns the withdrawal recipient, if any, otherwise the caller.
+    fn get_withdrawal_recipient(
+        &self,
+        caller: &ManagedAddress,
+        recipient: OptionalValue<ManagedAddress>,
+    ) -> ManagedAddress {
+        match recipient {
+            OptionalValue::Some(recipient) => {
+                require!(!recipient.is_zero(), ERROR_INVALID_ADDRESS);
+                recipient
+            }
+            OptionalValue::None => caller.clone(),
+        }
+    }
+
+    /// Subtracts a withdrawal from the strategy's quote token or DCA token balance and returns the token withdrawn.
+    /// A DCA token withdrawal removes its share of the cost basis, and resets the trailing take profit
+    /// high-water mark.
+    fn debit_strategy_balance(
+        &self,
+        attributes: &mut StrategyTokenAttributes<Self::Api>,
+        token_withdrawn: &TokenWithdrawn,
+        amount: &BigUint,
+    ) -> EgldOrEsdtTokenIdentifier {
+        match token_withdrawn {
+            TokenWithdrawn::QuoteToken => {
+                require!(
+                    &attributes.quote_token_balance >= amount && amount > &0,
+                    ERROR_INVALID_QUOTE_TOKEN_AMOUNT
+                );
+                attributes.quote_token_balance -= amount;
+                attributes.quote_token.clone()
+            }
+            TokenWithdrawn::DcaToken => {
+                require!(
+                    &attributes.dca_token_balance >= amount && amount > &0,
+                    ERROR_INVALID_DCA_TOKEN_AMOUNT
+                );
+                self.remove_from_position(attributes, amount);
+                attributes.take_profit_high_water_mark = BigUint::zero();
+                attributes.dca_token.clone()
+            }
+        }
+    }
+
+    /// Transfers a withdrawn amount to the recipient and emits `withdrawalMade`.
+    fn send_withdrawal(
+        &self,
+        withdrawer: &ManagedAddress,
+        recipient: &ManagedAddress,
+        nonce: u64,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.tx()
+            .to(recipient)
+            .egld_or_single_esdt(token, 0, amount)
+            .transfer();
+
+        self.event_withdrawal_made(withdrawer, recipient, &nonce, token, amount);
+    }
+
+    /// Requires the strategy to exist and not to be deleted: the smart contract holds its half of the strategy token NFT.
+    fn require_live_strategy(&self, nonce: u64) {
+        require!(
+            nonce > 0 && self.strategy_token().get_balance(nonce) > 0,
+            ERROR_INSUFFICIENT_STRATEGY_TOKEN_BALANCE
+        );
+    }
+
+    fn require_valid_quote_payment(
+        &self,
+        payment: &EgldOrEsdtTokenPayment,
+        quote_token: &QuoteToken<Self::Api>,
+    ) {
+        require!(
+            &payment.token_identifier == quote_token && payment.token_nonce == 0,
+            ERROR_INVALID_QUOTE_TOKEN
+        );
+        require!(
+            payment.amount > BigUint::zero(),
+            ERROR_INVALID_QUOTE_TOKEN_AMOUNT
+        );
+    }
+
+    /// Swaps a deposit made in a token other than the quote token to the strategy's quote token,
+    /// with the strategy's slippage, and returns the quote token amount received.
+    /// EGLD is wrapped before the swap; a WEGLD/EGLD deposit for an EGLD/WEGLD quote token is only (un)wrapped.
+    fn swap_deposit_to_quote_token(
+        &self,
+        payment: &EgldOrEsdtTokenPayment,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+        min_amount_out: BigUint,
+    ) -> BigUint {
         require!(
-            payment.token_identifier == self.get_usdc_identifier(),
-            ERROR_INVALID_USDC_TOKEN
+            payment.token_nonce == 0 && payment.amount > 0,
+            ERROR_INVALID_DEPOSIT_PAYMENT
         );
-        require!(payment.amount > BigUint::zero(), ERROR_INVALID_USDC_AMOUNT);
+        require!(min_amount_out > 0, ERROR_MIN_AMOUNT_OUT_REQUIRED);
+
+        if payment.token_identifier.is_egld() {
+            self.wrap_egld(&payment.amount);
+        }
+
+        let token_in = self.get_token_as_esdt(&payment.token_identifier);
+        let token_out = self.get_token_as_esdt(&attributes.quote_token);
+        let amount_out = if token_in == token_out {
+            payment.amount.clone()
+        } else {
+            self.execute_swap(
+                token_in,
+                payment.amount.clone(),
+                token_out,
+                self.get_strategy_slippage_percentage(attributes.max_slippage_percentage),
+            )
+        };
+        require!(amount_out >= min_amount_out, ERROR_MIN_AMOUNT_OUT_NOT_MET);
+
+        if attributes.quote_token.is_egld() {
+            self.unwrap_egld(&amount_out);
+        }
+
+        amount_out
     }
 
-    fn get_dca_token_usdc_equivalent(
+    /// Returns the current pool value of a DCA token amount, expressed in the quote token.
+    fn get_dca_token_quote_equivalent(
         &self,
-        dca_token_as_esdt: &TokenIdentifier,
+        dca_token: &DcaToken<Self::Api>,
+        quote_token: &QuoteToken<Self::Api>,
         dca_token_amount: &BigUint,
     ) -> BigUint {
         if dca_token_amount == &BigUint::zero() {
             return BigUint::zero();
         }
 
-        let swap_contracts =
-            self.get_swap_contracts(dca_token_as_esdt.clone(), self.get_usdc_identifier());
+        let swap_contracts = self.get_swap_contracts(
+            self.get_token_as_esdt(dca_token),
+            self.get_token_as_esdt(quote_token),
+        );
 
         if swap_contracts.is_empty() {
             return BigUint::zero();
@@ -535,33 +936,708 @@ pub trait StrategyModule:
         equivalent
     }
 
+    /// Adds a buy to the position: its quote token amount to the cost basis, the given fee cost basis to
+    /// the cost basis the profit fee is taken over, and its DCA token amount to the balance.
+    /// The buy is kept in the latest buys.
+    fn add_buy(
+        &self,
+        attributes: &mut StrategyTokenAttributes<Self::Api>,
+        buy: Swap<Self::Api>,
+        fee_cost_basis: &BigUint,
+    ) {
+        attributes.cost_basis += &buy.quote_token_amount;
+        attributes.fee_cost_basis += fee_cost_basis;
+        attributes.dca_token_balance += &buy.dca_token_amount;
+        self.push_swap(&mut attributes.buys, buy);
+    }
+
+    /// Records a sell, whose DCA token amount was already taken out of the position, in the latest sells.
+    /// While part of the position remains, the sell is the last sell its take profit is measured against.
+    fn add_sell(&self, attributes: &mut StrategyTokenAttributes<Self::Api>, sell: Swap<Self::Api>) {
+        if attributes.dca_token_balance > 0 {
+            attributes.last_sell = sell.clone();
+        }
+        self.push_swap(&mut attributes.sells, sell);
+    }
+
+    /// Takes a DCA token amount out of the position by a sell or a withdrawal, removing the share of the cost
+    /// basis and fee cost basis matching the share of the position taken out. Returns the fee cost basis
+    /// removed, the one a sell's profit fee is taken over.
+    ///
+    /// Emptying the position resets both cost bases and the last sell, so later buys start a new position.
+    fn remove_from_position(
+        &self,
+        attributes: &mut StrategyTokenAttributes<Self::Api>,
+        dca_token_amount: &BigUint,
+    ) -> BigUint {
+        if dca_token_amount >= &attributes.dca_token_balance {
+            attributes.dca_token_balance = BigUint::zero();
+            attributes.last_sell = Swap::default();
+            attributes.cost_basis = BigUint::zero();
+            return core::mem::replace(&mut attributes.fee_cost_basis, BigUint::zero());
+        }
+
+        let cost_basis_removed =
+            &attributes.cost_basis * dca_token_amount / &attributes.dca_token_balance;
+        let fee_cost_basis_removed =
+            &attributes.fee_cost_basis * dca_token_amount / &attributes.dca_token_balance;
+        attributes.cost_basis -= &cost_basis_removed;
+        attributes.fee_cost_basis -= &fee_cost_basis_removed;
+        attributes.dca_token_balance -= dca_token_amount;
+
+        fee_cost_basis_removed
+    }
+
+    /// Appends a swap to the latest buys or sells, dropping the oldest one beyond `MAX_SWAP_HISTORY`.
+    fn push_swap(&self, swaps: &mut ManagedVec<Swap<Self::Api>>, swap: Swap<Self::Api>) {
+        if swaps.len() >= MAX_SWAP_HISTORY {
+            swaps.remove(0);
+        }
+        swaps.push(swap);
+    }
+
+    /// Returns the quote token value the position must exceed, by the take profit percentage,
+    /// for the take profit condition to be met.
+    ///
+    /// After a partial take profit, this is the remaining position valued at the last sell price,
+    /// when higher than the cost basis, so the next take profit only happens on a further price increase.
+    fn get_take_profit_cost_basis(
+        &self,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+    ) -> BigUint {
+        let last_sell = &attributes.last_sell;
+        if last_sell.dca_token_amount == 0 {
+            return attributes.cost_basis.clone();
+        }
+
+        let last_sell_value = &attributes.dca_token_balance * &last_sell.quote_token_amount
+            / &last_sell.dca_token_amount;
+
+        attributes.cost_basis.clone().max(last_sell_value)
+    }
+
+    /// Returns the amount of DCA token sold on take profit: the take profit sell percentage
+    /// of the DCA token balance, or the whole balance if the percentage is zero.
+    fn get_take_profit_sell_amount(
+        &self,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+    ) -> BigUint {
+        if attributes.take_profit_sell_percentage == 0 {
+            return attributes.dca_token_balance.clone();
+        }
+
+        &attributes.dca_token_balance * attributes.take_profit_sell_percentage / MAX_PERCENTAGE
+    }
+
     fn is_in_profit(
         &self,
-        dca_token_usdc_equivalent: &BigUint,
+        dca_token_quote_equivalent: &BigUint,
         take_profit_percentage: u64,
-        buys: &ManagedVec<Swap<Self::Api>>,
-        sells: &ManagedVec<Swap<Self::Api>>,
+        cost_basis: &BigUint,
     ) -> bool {
-        let last_sell_ts_millis = sells
-            .iter()
-            .next_back()
-            .unwrap_or(Swap::default().as_ref())
-            .timestamp_millis;
-
-        let total_buys_after_last_sell = buys
-            .iter()
-            .filter(|swap| swap.timestamp_millis > last_sell_ts_millis)
-            .fold(BigUint::zero(), |acc, swap| acc + &swap.usdc_amount);
-
-        if total_buys_after_last_sell == BigUint::zero() {
+        if cost_basis == &BigUint::zero() {
             return false;
         }
 
-        let target_amount = total_buys_after_last_sell
+        let target_amount = cost_basis
+            .clone()
             .mul(BigUint::from(MAX_PERCENTAGE + take_profit_percentage))
             .div(BigUint::from(MAX_PERCENTAGE));
 
-        dca_token_usdc_equivalent >= &target_amount
+        dca_token_quote_equivalent >= &target_amount
+    }
+
+    /// Returns true if a high-water mark was recorded and the DCA token price retraced from it
+    /// by at least the trailing take profit percentage.
+    fn is_trailing_take_profit_retraced(
+        &self,
+        dca_token_price: &BigUint,
+        trailing_take_profit_percentage: u64,
+        high_water_mark: &BigUint,
+    ) -> bool {
+        if high_water_mark == &BigUint::zero() || dca_token_price == &BigUint::zero() {
+            return false;
+        }
+
+        let threshold_amount = high_water_mark
+            .clone()
+            .mul(BigUint::from(
+                MAX_PERCENTAGE - trailing_take_profit_percentage,
+            ))
+            .div(BigUint::from(MAX_PERCENTAGE));
+
+        dca_token_price <= &threshold_amount
+    }
+
+    /// Returns true if the position's quote token value dropped below its cost basis by at least
+    /// the stop loss percentage. A zero value means the position could not be priced, which is
+    /// never treated as a loss.
+    fn is_in_loss(
+        &self,
+        dca_token_quote_equivalent: &BigUint,
+        stop_loss_percentage: u64,
+        cost_basis: &BigUint,
+    ) -> bool {
+        if cost_basis == &BigUint::zero() || dca_token_quote_equivalent == &BigUint::zero() {
+            return false;
+        }
+
+        let threshold_amount = cost_basis
+            .clone()
+            .mul(BigUint::from(MAX_PERCENTAGE - stop_loss_percentage))
+            .div(BigUint::from(MAX_PERCENTAGE));
+
+        dca_token_quote_equivalent <= &threshold_amount
+    }
+
+    fn set_strategy_paused(&self, is_paused: bool) {
+        self.require_setup_is_complete();
+        self.require_not_paused();
+
+        let strategy_payment = self.call_value().single_esdt();
+        self.require_valid_strategy_payment(&strategy_payment);
+
+        let mut attributes = self.get_strategy_token_attributes(strategy_payment.token_nonce);
+        if is_paused {
+            require!(!attributes.is_paused, ERROR_STRATEGY_ALREADY_PAUSED);
+        } else {
+            require!(attributes.is_paused, ERROR_STRATEGY_NOT_PAUSED);
+
+            let ts_millis = self
+                .blockchain()
+                .get_block_timestamp_millis()
+                .as_u64_millis();
+            if attributes.frequency_in_millis > 0 && ts_millis > attributes.next_buy_ts_millis {
+                attributes.next_buy_ts_millis = self.get_next_buy_ts_millis(&attributes, ts_millis)
+                    - attributes.frequency_in_millis;
+            }
+        }
+        attributes.is_paused = is_paused;
+
+        self.strategy_token().nft_update_attributes(
+            strategy_payment.token_nonce,
+            &self.attributes_to_buffer(&attributes),
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.tx()
+            .to(&caller)
+            .payment(strategy_payment.clone())
+            .transfer();
+
+        if is_paused {
+            self.event_strategy_paused(&caller, &strategy_payment.token_nonce);
+        } else {
+            self.event_strategy_resumed(&caller, &strategy_payment.token_nonce);
+        }
+    }
+
+    /// Returns true if one of the strategy's end conditions is reached: the max number of buys,
+    /// the end timestamp, or a max total spend that the next buy would exceed.
+    fn is_strategy_completed(
+        &self,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+        ts_millis: TimestampInMillis,
+    ) -> bool {
+        if attributes.max_buys > 0 && self.get_scheduled_swaps(attributes) >= attributes.max_buys {
+            return true;
+        }
+
+        if attributes.end_ts_millis > 0 && ts_millis >= attributes.end_ts_millis {
+            return true;
+        }
+
+        if attributes.max_total_spend > 0 {
+            return &attributes.total_spent + &self.get_min_buy_amount(attributes)
+                > attributes.max_total_spend;
+        }
+
+        false
+    }
+
+    /// Returns the number of buys and sells made by the strategy's schedule, the ones counted by the max buys.
+    fn get_scheduled_swaps(&self, attributes: &StrategyTokenAttributes<Self::Api>) -> u64 {
+        attributes.scheduled_buys + attributes.scheduled_sells
+    }
+
+    /// Returns the timestamp of the first scheduled buy: the start timestamp, if any, or the current one.
+    fn get_first_buy_ts_millis(&self, start_ts_millis: TimestampInMillis) -> TimestampInMillis {
+        if start_ts_millis > 0 {
+            start_ts_millis
+        } else {
+            self.blockchain()
+                .get_block_timestamp_millis()
+                .as_u64_millis()
+        }
+    }
+
+    /// Returns the number of scheduled intervals a buy or scheduled sell at the given timestamp is made for.
+    ///
+    /// With the catch up policy, it is one per interval elapsed since the next buy was due, up to the
+    /// max catch up buys. It is then limited by the end conditions and, when buying, by the quote token balance.
+    fn get_swap_intervals(
+        &self,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+        ts_millis: TimestampInMillis,
+    ) -> u64 {
+        let mut intervals = match attributes.catch_up_policy {
+            CatchUpPolicy::Skip => 1,
+            CatchUpPolicy::CatchUp => {
+                let due_intervals = 1
+                    + (ts_millis - attributes.next_buy_ts_millis) / attributes.frequency_in_millis;
+                due_intervals.min(attributes.max_catch_up_buys)
+            }
+        };
+
+        if attributes.direction == StrategyDirection::Sell {
+            if attributes.max_buys > 0 {
+                intervals =
+                    intervals.min(attributes.max_buys - self.get_scheduled_swaps(attributes));
+            }
+            return intervals;
+        }
+
+        let min_buy_amount = self.get_min_buy_amount(attributes);
+        let affordable_intervals = (&attributes.quote_token_balance / &min_buy_amount)
+            .to_u64()
+            .unwrap_or(u64::MAX);
+        intervals = intervals.min(affordable_intervals);
+
+        if attributes.max_buys > 0 {
+            intervals = intervals.min(attributes.max_buys - self.get_scheduled_swaps(attributes));
+        }
+
+        if attributes.max_total_spend > 0 {
+            let budget_intervals = ((&attributes.max_total_spend - &attributes.total_spent)
+                / &min_buy_amount)
+                .to_u64()
+                .unwrap_or(u64::MAX);
+            intervals = intervals.min(budget_intervals);
+        }
+
+        intervals
+    }
+
+    /// Returns the least quote token amount a buy spends per scheduled interval: the amount per swap,
+    /// or the min amount per swap with value averaging and percentage of balance.
+    fn get_min_buy_amount(&self, attributes: &StrategyTokenAttributes<Self::Api>) -> BigUint {
+        match attributes.buy_sizing_mode {
+            BuySizingMode::Fixed => attributes.amount_per_swap.clone(),
+            BuySizingMode::ValueAveraging | BuySizingMode::PercentageOfBalance => {
+                attributes.min_amount_per_swap.clone()
+            }
+        }
+    }
+
+    /// Returns the quote token amount a buy for the given number of scheduled intervals spends.
+    ///
+    /// With value averaging, it is the amount bringing the position's quote token value up to the target path,
+    /// the amount per swap times the scheduled buys made after this one, kept between the min and max amount
+    /// per swap for each interval. With percentage of balance, it is the balance percentage per swap of the quote token
+    /// balance at execution time, at least the min amount per swap, for each interval.
+    /// When buying the dip, the amount is multiplied by the dip multiplier.
+    /// It is then limited by the quote token balance and the max total spend.
+    fn get_buy_amount(
+        &self,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+        intervals: u64,
+    ) -> BigUint {
+        let mut buy_amount = match attributes.buy_sizing_mode {
+            BuySizingMode::Fixed => &attributes.amount_per_swap * intervals,
+            BuySizingMode::ValueAveraging => {
+                self.get_value_averaging_buy_amount(attributes, intervals)
+            }
+            BuySizingMode::PercentageOfBalance => {
+                let balance_amount = &attributes.quote_token_balance
+                    * attributes.balance_percentage_per_swap
+                    / MAX_PERCENTAGE;
+                if balance_amount > attributes.min_amount_per_swap {
+                    balance_amount * intervals
+                } else {
+                    &attributes.min_amount_per_swap * intervals
+                }
+            }
+        };
+
+        if self.is_buying_the_dip(attributes) {
+            buy_amount = buy_amount * attributes.dip_multiplier_percentage / MAX_PERCENTAGE;
+        }
+
+        if buy_amount > attributes.quote_token_balance {
+            buy_amount = attributes.quote_token_balance.clone();
+        }
+        if attributes.max_total_spend > 0 {
+            let budget = &attributes.max_total_spend - &attributes.total_spent;
+            if buy_amount > budget {
+                buy_amount = budget;
+            }
+        }
+
+        buy_amount
+    }
+
+    fn get_value_averaging_buy_amount(
+        &self,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+        intervals: u64,
+    ) -> BigUint {
+        let target_value = &attributes.amount_per_swap * (attributes.scheduled_buys + intervals);
+        let position_value = self.get_dca_token_quote_equivalent(
+            &attributes.dca_token,
+            &attributes.quote_token,
+            &attributes.dca_token_balance,
+        );
+        let buy_amount = if target_value > position_value {
+            target_value - position_value
+        } else {
+            BigUint::zero()
+        };
+
+        let min_buy_amount = &attributes.min_amount_per_swap * intervals;
+        let max_buy_amount = &attributes.max_amount_per_swap * intervals;
+        if buy_amount < min_buy_amount {
+            min_buy_amount
+        } else if buy_amount > max_buy_amount {
+            max_buy_amount
+        } else {
+            buy_amount
+        }
+    }
+
+    /// Returns true if the strategy has no max buy price, or if the current pool price of the DCA token,
+    /// the quote token value of a whole DCA token, is at or below it.
+    fn is_within_max_buy_price(&self, attributes: &StrategyTokenAttributes<Self::Api>) -> bool {
+        if attributes.max_buy_price == 0 {
+            return true;
+        }
+
+        let price = self.get_dca_token_price(attributes);
+
+        price > 0 && price <= attributes.max_buy_price
+    }
+
+    /// Returns the current pool price of the DCA token: the quote token value of a whole DCA token
+    /// (10^decimals units), converted hop by hop at the pool reserve ratios, without fees or price impact.
+    fn get_dca_token_price(&self, attributes: &StrategyTokenAttributes<Self::Api>) -> BigUint {
+        let swap_contracts = self.get_swap_contracts(
+            self.get_token_as_esdt(&attributes.dca_token),
+            self.get_token_as_esdt(&attributes.quote_token),
+        );
+
+        if swap_contracts.is_empty() {
+            return BigUint::zero();
+        }
+
+        let mut price = self.get_dca_token_unit(attributes);
+        for contract_data in swap_contracts.into_iter() {
+            price = self.get_equivalent(
+                contract_data.address.clone(),
+                contract_data.first_token_id,
+                price,
+            );
+        }
+
+        price
+    }
+
+    /// Returns the amount of units in a whole DCA token: 10^decimals.
+    fn get_dca_token_unit(&self, attributes: &StrategyTokenAttributes<Self::Api>) -> BigUint {
+        BigUint::from(10u64).pow(self.get_dca_token_decimals(&attributes.dca_token))
+    }
+
+    /// Returns the DCA token amount a scheduled sell made for the given number of intervals sells:
+    /// the DCA token amount per swap, or the DCA token amount worth the amount per swap at the pool price,
+    /// per interval, capped at the DCA token balance.
+    fn get_scheduled_sell_amount(
+        &self,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+        intervals: u64,
+    ) -> BigUint {
+        let sell_amount_per_interval = if attributes.dca_token_amount_per_swap > 0 {
+            attributes.dca_token_amount_per_swap.clone()
+        } else {
+            let price = self.get_dca_token_price(attributes);
+            if price == 0 {
+                return BigUint::zero();
+            }
+            &attributes.amount_per_swap * self.get_dca_token_unit(attributes) / price
+        };
+
+        let sell_amount = sell_amount_per_interval * intervals;
+        if sell_amount > attributes.dca_token_balance {
+            attributes.dca_token_balance.clone()
+        } else {
+            sell_amount
+        }
+    }
+
+    /// Returns true if the strategy buys the dip: the current pool price of the DCA token is more than the
+    /// dip threshold percentage below either the average entry price of the position or the last buy's price.
+    ///
+    /// Prices are per whole DCA token, so the size of the position does not affect the comparison.
+    fn is_buying_the_dip(&self, attributes: &StrategyTokenAttributes<Self::Api>) -> bool {
+        if attributes.dip_threshold_percentage == 0 || attributes.dca_token_balance == 0 {
+            return false;
+        }
+
+        let unit = self.get_dca_token_unit(attributes);
+        let average_entry_price = &attributes.cost_basis * &unit / &attributes.dca_token_balance;
+        let last_buy_price = match attributes.buys.iter().next_back() {
+            Some(last_buy) if last_buy.dca_token_amount > 0 => {
+                &last_buy.quote_token_amount * &unit / &last_buy.dca_token_amount
+            }
+            _ => BigUint::zero(),
+        };
+        let reference_price = last_buy_price.max(average_entry_price);
+
+        let price = self.get_dca_token_price(attributes);
+
+        price > 0
+            && price * MAX_PERCENTAGE
+                < reference_price * (MAX_PERCENTAGE - attributes.dip_threshold_percentage)
+    }
+
+    /// Returns the first scheduled buy timestamp after the given one, keeping the schedule
+    /// anchored to the previous scheduled buy timestamp.
+    fn get_next_buy_ts_millis(
+        &self,
+        attributes: &StrategyTokenAttributes<Self::Api>,
+        ts_millis: TimestampInMillis,
+    ) -> TimestampInMillis {
+        let elapsed_intervals =
+            1 + (ts_millis - attributes.next_buy_ts_millis) / attributes.frequency_in_millis;
+
+        attributes.next_buy_ts_millis + elapsed_intervals * attributes.frequency_in_millis
+    }
+
+    /// Validates the settings of a strategy config, except for the start timestamp, which is only
+    /// validated when it is set or changed.
+    fn require_valid_strategy_config(
+        &self,
+        quote_token: &QuoteToken<Self::Api>,
+        config: &StrategyConfig<Self::Api>,
+    ) {
+        self.require_valid_amount_per_swap(quote_token, &config.amount_per_swap);
+        self.require_valid_frequency_duration(config.frequency_in_millis);
+        self.require_valid_max_slippage_percentage(config.max_slippage_percentage);
+        self.require_valid_stop_loss_percentage(config.stop_loss_percentage);
+        self.require_valid_take_profit_sell_percentage(config.take_profit_sell_percentage);
+        self.require_valid_trailing_take_profit_percentage(config.trailing_take_profit_percentage);
+        self.require_valid_buy_sizing(
+            quote_token,
+            &config.buy_sizing_mode,
+            &config.min_amount_per_swap,
+            &config.max_amount_per_swap,
+            config.balance_percentage_per_swap,
+        );
+        self.require_valid_dip_multiplier(
+            config.dip_threshold_percentage,
+            config.dip_multiplier_percentage,
+        );
+        self.require_valid_direction(
+            &config.direction,
+            &config.dca_token_amount_per_swap,
+            &config.buy_sizing_mode,
+            config.dip_threshold_percentage,
+            &config.max_buy_price,
+            &config.max_total_spend,
+        );
+        self.require_valid_catch_up_policy(&config.catch_up_policy, config.max_catch_up_buys);
+        self.require_valid_end_conditions(
+            &config.amount_per_swap,
+            config.start_ts_millis,
+            config.end_ts_millis,
+            &config.max_total_spend,
+        );
+    }
+
+    /// Copies the settings of a strategy config into the strategy token attributes.
+    fn apply_strategy_config(
+        &self,
+        attributes: &mut StrategyTokenAttributes<Self::Api>,
+        config: &StrategyConfig<Self::Api>,
+    ) {
+        attributes.amount_per_swap = config.amount_per_swap.clone();
+        attributes.dca_frequency = self.get_frequency_name(config.frequency_in_millis);
+        attributes.frequency_in_millis = config.frequency_in_millis;
+        attributes.take_profit_percentage = config.take_profit_percentage;
+        attributes.max_slippage_percentage = config.max_slippage_percentage;
+        attributes.stop_loss_percentage = config.stop_loss_percentage;
+        attributes.take_profit_sell_percentage = config.take_profit_sell_percentage;
+        attributes.trailing_take_profit_percentage = config.trailing_take_profit_percentage;
+        attributes.post_take_profit_action = config.post_take_profit_action.clone();
+        attributes.start_ts_millis = config.start_ts_millis;
+        attributes.catch_up_policy = config.catch_up_policy.clone();
+        attributes.max_catch_up_buys = config.max_catch_up_buys;
+        attributes.max_buys = config.max_buys;
+        attributes.end_ts_millis = config.end_ts_millis;
+        attributes.max_total_spend = config.max_total_spend.clone();
+        attributes.buy_sizing_mode = config.buy_sizing_mode.clone();
+        attributes.min_amount_per_swap = config.min_amount_per_swap.clone();
+        attributes.max_amount_per_swap = config.max_amount_per_swap.clone();
+        attributes.dip_threshold_percentage = config.dip_threshold_percentage;
+        attributes.dip_multiplier_percentage = config.dip_multiplier_percentage;
+        attributes.balance_percentage_per_swap = config.balance_percentage_per_swap;
+        attributes.max_buy_price = config.max_buy_price.clone();
+        attributes.direction = config.direction.clone();
+        attributes.dca_token_amount_per_swap = config.dca_token_amount_per_swap.clone();
+    }
+
+    /// Validates the sizing settings of the buy sizing mode and requires the others to be zero:
+    /// * value averaging - a non-zero min amount per swap valid for the quote token and a max amount per swap
+    ///   at least as high
+    /// * percentage of balance - a non-zero min amount per swap valid for the quote token and a balance
+    ///   percentage per swap above 0% and up to 100%
+    ///
+    /// The min amount per swap bounds the number of intervals a buy can afford, so it cannot be zero.
+    fn require_valid_buy_sizing(
+        &self,
+        quote_token: &QuoteToken<Self::Api>,
+        buy_sizing_mode: &BuySizingMode,
+        min_amount_per_swap: &BigUint,
+        max_amount_per_swap: &BigUint,
+        balance_percentage_per_swap: Percentage,
+    ) {
+        let is_valid = match buy_sizing_mode {
+            BuySizingMode::Fixed => {
+                min_amount_per_swap == &0
+                    && max_amount_per_swap == &0
+                    && balance_percentage_per_swap == 0
+            }
+            BuySizingMode::ValueAveraging => {
+                self.require_valid_amount_per_swap(quote_token, min_amount_per_swap);
+                min_amount_per_swap > &0
+                    && max_amount_per_swap >= min_amount_per_swap
+                    && balance_percentage_per_swap == 0
+            }
+            BuySizingMode::PercentageOfBalance => {
+                self.require_valid_amount_per_swap(quote_token, min_amount_per_swap);
+                min_amount_per_swap > &0
+                    && max_amount_per_swap == &0
+                    && balance_percentage_per_swap > 0
+                    && balance_percentage_per_swap <= MAX_PERCENTAGE
+            }
+        };
+        require!(is_valid, ERROR_INVALID_BUY_SIZING);
+    }
+
+    /// Validates that buying the dip is either disabled, with both percentages zero, or has a threshold
+    /// below 100% and a multiplier above 1x, up to `MAX_DIP_MULTIPLIER_PERCENTAGE`.
+    fn require_valid_dip_multiplier(
+        &self,
+        dip_threshold_percentage: Percentage,
+        dip_multiplier_percentage: Percentage,
+    ) {
+        let is_disabled = dip_threshold_percentage == 0 && dip_multiplier_percentage == 0;
+        let is_valid = dip_threshold_percentage > 0
+            && dip_threshold_percentage < MAX_PERCENTAGE
+            && dip_multiplier_percentage > MAX_PERCENTAGE
+            && dip_multiplier_percentage <= MAX_DIP_MULTIPLIER_PERCENTAGE;
+        require!(is_disabled || is_valid, ERROR_INVALID_DIP_MULTIPLIER);
+    }
+
+    /// Validates the settings of the strategy direction: a buying strategy has no DCA token amount per swap,
+    /// and a selling one uses fixed sizing, without a dip multiplier, max buy price or max total spend.
+    fn require_valid_direction(
+        &self,
+        direction: &StrategyDirection,
+        dca_token_amount_per_swap: &BigUint,
+        buy_sizing_mode: &BuySizingMode,
+        dip_threshold_percentage: Percentage,
+        max_buy_price: &BigUint,
+        max_total_spend: &BigUint,
+    ) {
+        let is_valid = match direction {
+            StrategyDirection::Buy => dca_token_amount_per_swap == &0,
+            StrategyDirection::Sell => {
+                buy_sizing_mode == &BuySizingMode::Fixed
+                    && dip_threshold_percentage == 0
+                    && max_buy_price == &0
+                    && max_total_spend == &0
+            }
+        };
+        require!(is_valid, ERROR_INVALID_STRATEGY_DIRECTION);
+    }
+
+    /// Validates that the max catch up buys is between 1 and `MAX_CATCH_UP_BUYS` with the
+    /// catch up policy, and zero otherwise.
+    fn require_valid_catch_up_policy(
+        &self,
+        catch_up_policy: &CatchUpPolicy,
+        max_catch_up_buys: u64,
+    ) {
+        let is_valid = match catch_up_policy {
+            CatchUpPolicy::Skip => max_catch_up_buys == 0,
+            CatchUpPolicy::CatchUp => {
+                max_catch_up_buys > 0 && max_catch_up_buys <= MAX_CATCH_UP_BUYS
+            }
+        };
+        require!(is_valid, ERROR_INVALID_MAX_CATCH_UP_BUYS);
+    }
+
+    /// Validates that the start timestamp, if any, is in the future.
+    fn require_valid_start_timestamp(&self, start_ts_millis: TimestampInMillis) {
+        require!(
+            start_ts_millis == 0
+                || start_ts_millis
+                    > self
+                        .blockchain()
+                        .get_block_timestamp_millis()
+                        .as_u64_millis(),
+            ERROR_INVALID_START_TIMESTAMP
+        );
+    }
+
+    /// Validates that the end timestamp, if any, is in the future and after the start timestamp,
+    /// and that the max total spend, if any, allows at least one buy.
+    fn require_valid_end_conditions(
+        &self,
+        amount_per_swap: &BigUint,
+        start_ts_millis: TimestampInMillis,
+        end_ts_millis: TimestampInMillis,
+        max_total_spend: &BigUint,
+    ) {
+        require!(
+            end_ts_millis == 0
+                || (end_ts_millis > start_ts_millis
+                    && end_ts_millis
+                        > self
+                            .blockchain()
+                            .get_block_timestamp_millis()
+                            .as_u64_millis()),
+            ERROR_INVALID_END_TIMESTAMP
+        );
+        require!(
+            max_total_spend == &BigUint::zero() || max_total_spend >= amount_per_swap,
+            ERROR_INVALID_MAX_TOTAL_SPEND
+        );
+    }
+
+    /// Validates that the take profit sell percentage is at most 100%.
+    fn require_valid_take_profit_sell_percentage(&self, take_profit_sell_percentage: Percentage) {
+        require!(
+            take_profit_sell_percentage <= MAX_PERCENTAGE,
+            ERROR_INVALID_TAKE_PROFIT_SELL_PERCENTAGE
+        );
+    }
+
+    /// Validates that the trailing take profit percentage is below 100%.
+    fn require_valid_trailing_take_profit_percentage(
+        &self,
+        trailing_take_profit_percentage: Percentage,
+    ) {
+        require!(
+            trailing_take_profit_percentage < MAX_PERCENTAGE,
+            ERROR_INVALID_TRAILING_TAKE_PROFIT_PERCENTAGE
+        );
+    }
+
+    /// Validates that the stop loss percentage is below 100%.
+    fn require_valid_stop_loss_percentage(&self, stop_loss_percentage: Percentage) {
+        require!(
+            stop_loss_percentage < MAX_PERCENTAGE,
+            ERROR_INVALID_STOP_LOSS_PERCENTAGE
+        );
     }
 
     // === Events ===
@@ -571,9 +1647,9 @@ pub trait StrategyModule:
         &self,
         #[indexed] creator: &ManagedAddress,
         #[indexed] nonce: &u64,
-        #[indexed] amount_per_swap: &BigUint,
-        #[indexed] frequency: &DcaFrequency<Self::Api>,
-        #[indexed] take_profit_percentage: &Percentage,
+        #[indexed] dca_token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] quote_token: &EgldOrEsdtTokenIdentifier,
+        config: &StrategyConfig<Self::Api>,
     );
 
     #[event("strategyModified")]
@@ -581,9 +1657,7 @@ pub trait StrategyModule:
         &self,
         #[indexed] modifier: &ManagedAddress,
         #[indexed] nonce: &u64,
-        #[indexed] amount_per_swap: &BigUint,
-        #[indexed] frequency: &DcaFrequency<Self::Api>,
-        #[indexed] take_profit_percentage: &Percentage,
+        config: &StrategyConfig<Self::Api>,
     );
 
     #[event("strategyDeleted")]
@@ -591,22 +1665,57 @@ pub trait StrategyModule:
         &self,
         #[indexed] deleter: &ManagedAddress,
         #[indexed] nonce: &u64,
-        #[indexed] usdc_amount: &BigUint,
+        #[indexed] quote_token_amount: &BigUint,
         #[indexed] token_amount: &BigUint,
     );
 
+    #[event("strategyPaused")]
+    fn event_strategy_paused(&self, #[indexed] pauser: &ManagedAddress, #[indexed] nonce: &u64);
+
+    #[event("strategyResumed")]
+    fn event_strategy_resumed(&self, #[indexed] resumer: &ManagedAddress, #[indexed] nonce: &u64);
+
     #[event("depositMade")]
     fn event_deposit_made(
         &self,
         #[indexed] depositor: &ManagedAddress,
         #[indexed] nonce: &u64,
-        #[indexed] usdc_amount: &BigUint,
+        #[indexed] quote_token_amount: &BigUint,
+    );
+
+    #[event("dcaTokenImported")]
+    fn event_dca_token_imported(
+        &self,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] nonce: &u64,
+        #[indexed] dca_token_amount: &BigUint,
+        #[indexed] cost_basis: &BigUint,
+    );
+
+    #[event("depositMadeFor")]
+    fn event_deposit_made_for(
+        &self,
+        #[indexed] funder: &ManagedAddress,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] nonce: &u64,
+        #[indexed] quote_token_amount: &BigUint,
+    );
+
+    #[event("depositSwapped")]
+    fn event_deposit_swapped(
+        &self,
+        #[indexed] depositor: &ManagedAddress,
+        #[indexed] nonce: &u64,
+        #[indexed] token_in: &EgldOrEsdtTokenIdentifier,
+        #[indexed] amount_in: &BigUint,
+        #[indexed] quote_token_amount: &BigUint,
     );
 
     #[event("withdrawalMade")]
     fn event_withdrawal_made(
         &self,
         #[indexed] withdrawer: &ManagedAddress,
+        #[indexed] recipient: &ManagedAddress,
         #[indexed] nonce: &u64,
         #[indexed] token: &EgldOrEsdtTokenIdentifier,
         #[indexed] amount: &BigUint,
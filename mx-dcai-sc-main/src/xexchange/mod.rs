@@ -7,6 +7,8 @@
 //! * `pair_proxy` - Proxy for calling pair contract endpoints
 //! * `router_module` - Interaction with xExchange router for multi-hop swaps
 //! * `router_proxy` - Proxy for calling router contract endpoints
+//! * `safe_price_module` - Validation of swap outputs against the xExchange safe price
+//! * `safe_price_proxy` - Proxy for calling the safe price view contract
 //! * `wrapper_module` - EGLD/WEGLD wrapping and unwrapping functionality
 //! * `wrapper_proxy` - Proxy for calling wrapper contract endpoints
 
@@ -14,5 +16,7 @@ pub mod pair_module;
 pub mod pair_proxy;
 pub mod router_module;
 pub mod router_proxy;
+pub mod safe_price_module;
+pub mod safe_price_proxy;
 pub mod wrapper_module;
 pub mod wrapper_proxy;
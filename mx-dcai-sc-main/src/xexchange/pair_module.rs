@@ -1,29 +1,69 @@
 use multiversx_sc::imports::*;
 
-use constants::{USDC_IDENTIFIER, WEGLD_IDENTIFIER};
+use errors::ERROR_INVALID_TOKEN_IDENTIFIER;
 
 use super::pair_proxy;
 
 /// Smart Contract module for interacting with xExchange pair contracts.
 ///
 /// This module provides:
+/// * Owner endpoints for setting the USDC and WEGLD token identifiers
 /// * Views for getting USDC and WEGLD token identifiers
 /// * Methods to query swap amounts from pair contracts
 /// * Methods to get token equivalents based on pool reserves
+/// * Methods to query the reserves of pair contracts
 #[multiversx_sc::module]
 pub trait XExchangePairModule {
+    // === Endpoints ===
+
+    /// Sets the USDC token identifier.
+    ///
+    /// Only the contract owner can call this endpoint.
+    ///
+    /// ### Arguments
+    /// * `usdc_identifier` - The USDC token identifier on the current network.
+    #[only_owner]
+    #[endpoint(setUsdcIdentifier)]
+    fn set_usdc_identifier(&self, usdc_identifier: TokenIdentifier) {
+        require!(
+            usdc_identifier.is_valid_esdt_identifier(),
+            ERROR_INVALID_TOKEN_IDENTIFIER
+        );
+
+        self.usdc_identifier().set(&usdc_identifier);
+        self.event_usdc_identifier_set(&usdc_identifier);
+    }
+
+    /// Sets the WEGLD (Wrapped EGLD) token identifier.
+    ///
+    /// Only the contract owner can call this endpoint.
+    ///
+    /// ### Arguments
+    /// * `wegld_identifier` - The WEGLD token identifier on the current network.
+    #[only_owner]
+    #[endpoint(setWegldIdentifier)]
+    fn set_wegld_identifier(&self, wegld_identifier: TokenIdentifier) {
+        require!(
+            wegld_identifier.is_valid_esdt_identifier(),
+            ERROR_INVALID_TOKEN_IDENTIFIER
+        );
+
+        self.wegld_identifier().set(&wegld_identifier);
+        self.event_wegld_identifier_set(&wegld_identifier);
+    }
+
     // === Views ===
 
     /// Returns the USDC token identifier.
     #[view(getUsdcIdentifier)]
     fn get_usdc_identifier(&self) -> TokenIdentifier {
-        TokenIdentifier::from_esdt_bytes(USDC_IDENTIFIER)
+        self.usdc_identifier().get()
     }
 
     /// Returns the WEGLD (Wrapped EGLD) token identifier.
     #[view(getWegldIdentifier)]
     fn get_wegld_identifier(&self) -> TokenIdentifier {
-        TokenIdentifier::from_esdt_bytes(WEGLD_IDENTIFIER)
+        self.wegld_identifier().get()
     }
 
     // === Private ===
@@ -78,4 +118,41 @@ pub trait XExchangePairModule {
             .returns(ReturnsResult)
             .sync_call_readonly()
     }
+
+    /// Queries a pair contract to get its reserve of a token.
+    ///
+    /// ### Arguments
+    /// * `contract_address` - The address of the pair contract.
+    /// * `token_id` - The token to get the reserve of.
+    ///
+    /// ### Returns
+    /// The amount of the token in the pool.
+    fn get_reserve(
+        &self,
+        contract_address: &ManagedAddress,
+        token_id: &TokenIdentifier,
+    ) -> BigUint {
+        self.tx()
+            .to(contract_address)
+            .typed(pair_proxy::PairProxy)
+            .pair_reserve(token_id)
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+    }
+
+    // === Storage ===
+
+    #[storage_mapper("usdc_identifier")]
+    fn usdc_identifier(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("wegld_identifier")]
+    fn wegld_identifier(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    // === Events ===
+
+    #[event("usdcIdentifierSet")]
+    fn event_usdc_identifier_set(&self, #[indexed] usdc_identifier: &TokenIdentifier);
+
+    #[event("wegldIdentifierSet")]
+    fn event_wegld_identifier_set(&self, #[indexed] wegld_identifier: &TokenIdentifier);
 }
@@ -75,4 +75,17 @@ where
             .argument(&amount_in)
             .original_result()
     }
+
+    pub fn pair_reserve<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReserve")
+            .argument(&token_id)
+            .original_result()
+    }
 }
@@ -1,5 +1,6 @@
 use super::router_proxy::{self, PairContractMetadata};
-use constants::{MAX_PERCENTAGE, SWAP_TOKENS_FIXED_INPUT_FUNC_NAME, XEXCHANGE_ROUTER_SC_ADDRESS};
+use constants::{MAX_PERCENTAGE, SWAP_TOKENS_FIXED_INPUT_FUNC_NAME};
+use errors::{ERROR_INVALID_ADDRESS, ERROR_PRICE_DEVIATION_TOO_HIGH};
 use multiversx_sc::imports::*;
 
 /// Type alias for swap operation parameters.
@@ -15,18 +16,41 @@ pub type SwapOperationType<M> =
 /// Smart Contract module for interacting with the xExchange router.
 ///
 /// This module provides:
+/// * An owner endpoint to set the router contract address
 /// * Views to get the router contract address
 /// * Methods to find optimal swap paths between tokens
 /// * Methods to execute multi-hop swaps through the router
 /// * Support for direct swaps and multi-pair routing (via WEGLD or USDC)
+/// * Validation of the swap output against the safe price
 #[multiversx_sc::module]
-pub trait XExchangeRouterModule: crate::xexchange::pair_module::XExchangePairModule {
+pub trait XExchangeRouterModule:
+    crate::admins::AdminsModule
+    + crate::xexchange::pair_module::XExchangePairModule
+    + crate::xexchange::safe_price_module::SafePriceModule
+{
+    // === Endpoints ===
+
+    /// Sets the xExchange router smart contract address.
+    ///
+    /// Only the contract owner can call this endpoint.
+    ///
+    /// ### Arguments
+    /// * `address` - The router contract address on the current network.
+    #[only_owner]
+    #[endpoint(setXexchangeRouterScAddress)]
+    fn set_xexchange_router_sc_address(&self, address: ManagedAddress) {
+        require!(!address.is_zero(), ERROR_INVALID_ADDRESS);
+
+        self.xexchange_router_sc_address().set(&address);
+        self.event_xexchange_router_sc_address_set(&address);
+    }
+
     // === Views ===
 
     /// Returns the xExchange router smart contract address.
     #[view(getXexchangeRouterScAddress)]
     fn get_xexchange_router_sc_address(&self) -> ManagedAddress {
-        ManagedAddress::new_from_bytes(&XEXCHANGE_ROUTER_SC_ADDRESS)
+        self.xexchange_router_sc_address().get()
     }
 
     // === Private ===
@@ -37,24 +61,19 @@ pub trait XExchangeRouterModule: crate::xexchange::pair_module::XExchangePairMod
             .get_sc_balance(EgldOrEsdtTokenIdentifier::esdt(token), 0u64)
     }
 
-    /// Gets the balance of a token held by a specific address.
-    fn get_token_balance_in_address(
-        &self,
-        address: &ManagedAddress,
-        token: &TokenIdentifier,
-    ) -> BigUint {
-        self.blockchain().get_esdt_balance(address, token, 0u64)
-    }
-
     /// Checks if a pair contract has liquidity for both tokens.
+    ///
+    /// Reads the pair's reserves rather than its token balances, since the reserves are
+    /// what the pool prices from. A zero address has no liquidity.
     fn get_contract_has_balance(
         &self,
         address: &ManagedAddress,
         token_a: &TokenIdentifier,
         token_b: &TokenIdentifier,
     ) -> bool {
-        self.get_token_balance_in_address(address, token_a) > 0
-            && self.get_token_balance_in_address(address, token_b) > 0
+        !address.is_zero()
+            && self.get_reserve(address, token_a) > 0
+            && self.get_reserve(address, token_b) > 0
     }
 
     /// Queries the router to get the pair contract address for two tokens.
@@ -285,6 +304,9 @@ pub trait XExchangeRouterModule: crate::xexchange::pair_module::XExchangePairMod
 
     /// Executes a token swap with automatic path finding.
     ///
+    /// The swap reverts when its output is below the safe price equivalent of the input
+    /// minus the maximum price deviation, or when no safe price view contract is configured.
+    ///
     /// ### Arguments
     /// * `token_in` - The input token identifier.
     /// * `amount_in` - The amount of input tokens.
@@ -302,14 +324,25 @@ pub trait XExchangeRouterModule: crate::xexchange::pair_module::XExchangePairMod
     ) -> BigUint {
         let swap_contracts = self.get_swap_contracts(token_in.clone(), token_out.clone());
 
-        self.require_has_contracts(&swap_contracts.to_vec());
+        let swap_contracts_vec = swap_contracts.to_vec();
+        self.require_has_contracts(&swap_contracts_vec);
+
+        let safe_price_min_amount_out =
+            self.get_safe_price_min_amount_out(&amount_in, &swap_contracts_vec);
 
         let swap_operations = self.get_swap_operations(amount_in.clone(), slippage, swap_contracts);
 
         let payments =
             self.multi_pair_swap(token_in.clone(), amount_in.clone(), swap_operations.clone());
 
-        payments.into_iter().next_back().unwrap().into_tuple().2
+        let amount_out = payments.into_iter().next_back().unwrap().into_tuple().2;
+
+        require!(
+            amount_out >= safe_price_min_amount_out,
+            ERROR_PRICE_DEVIATION_TOO_HIGH
+        );
+
+        amount_out
     }
 
     /// Validates that at least one pair contract is available for the swap.
@@ -319,4 +352,14 @@ pub trait XExchangeRouterModule: crate::xexchange::pair_module::XExchangePairMod
             "At least one pair contract is required for a swap operation."
         );
     }
+
+    // === Storage ===
+
+    #[storage_mapper("xexchange_router_sc_address")]
+    fn xexchange_router_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // === Events ===
+
+    #[event("xexchangeRouterScAddressSet")]
+    fn event_xexchange_router_sc_address_set(&self, #[indexed] address: &ManagedAddress);
 }
@@ -0,0 +1,141 @@
+use constants::{DEFAULT_MAX_PRICE_DEVIATION, MAX_PERCENTAGE};
+use errors::{ERROR_INVALID_ADDRESS, ERROR_INVALID_PERCENTAGE, ERROR_SAFE_PRICE_VIEW_NOT_SET};
+use multiversx_sc::imports::*;
+
+use super::{router_proxy::PairContractMetadata, safe_price_proxy};
+
+/// Smart Contract module for validating swaps against the xExchange safe price (TWAP).
+///
+/// This module provides:
+/// * An owner endpoint to set the safe price view contract address
+/// * An admin endpoint to set the maximum allowed deviation from the safe price
+/// * Views to get both settings
+/// * A method to compute the minimum acceptable output of a swap path
+///
+/// The spot based minimum computed in `get_swap_operations` moves together with the pools,
+/// so it cannot protect against a sandwich. The safe price is a time weighted average that
+/// a single transaction cannot move, which makes it a usable independent reference.
+#[multiversx_sc::module]
+pub trait SafePriceModule: crate::admins::AdminsModule {
+    // === Endpoints ===
+
+    /// Sets the xExchange safe price view smart contract address.
+    ///
+    /// Only the contract owner can call this endpoint.
+    /// The address is also set by `init` and `upgrade`; bot swaps refuse to run without it.
+    ///
+    /// ### Arguments
+    /// * `address` - The safe price view contract address on the current network.
+    #[only_owner]
+    #[endpoint(setSafePriceViewScAddress)]
+    fn set_safe_price_view_sc_address(&self, address: ManagedAddress) {
+        require!(!address.is_zero(), ERROR_INVALID_ADDRESS);
+
+        self.safe_price_view_sc_address().set(&address);
+        self.event_safe_price_view_sc_address_set(&address);
+    }
+
+    /// Sets the maximum deviation of a swap's output from its safe price equivalent.
+    ///
+    /// Only admins can call this endpoint.
+    /// If not set, the default maximum deviation will be used.
+    ///
+    /// ### Arguments
+    /// * `max_price_deviation_percentage` - The maximum deviation, in basis points.
+    #[endpoint(setMaxPriceDeviationPercentage)]
+    fn set_max_price_deviation_percentage(&self, max_price_deviation_percentage: u64) {
+        self.require_is_admin(&self.blockchain().get_caller());
+        require!(
+            max_price_deviation_percentage < MAX_PERCENTAGE,
+            ERROR_INVALID_PERCENTAGE
+        );
+
+        self.event_max_price_deviation_percentage_set(max_price_deviation_percentage);
+        self.max_price_deviation_percentage()
+            .set(max_price_deviation_percentage);
+    }
+
+    // === Views ===
+
+    /// Returns the xExchange safe price view smart contract address.
+    #[view(getSafePriceViewScAddress)]
+    fn get_safe_price_view_sc_address(&self) -> OptionalValue<ManagedAddress> {
+        if self.safe_price_view_sc_address().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.safe_price_view_sc_address().get())
+        }
+    }
+
+    /// Returns the maximum deviation percentage to use (custom if set, otherwise default).
+    #[view(getMaxPriceDeviationPercentage)]
+    fn get_final_max_price_deviation_percentage(&self) -> u64 {
+        if self.max_price_deviation_percentage().is_empty() {
+            DEFAULT_MAX_PRICE_DEVIATION
+        } else {
+            self.max_price_deviation_percentage().get()
+        }
+    }
+
+    // === Private ===
+
+    /// Computes the minimum acceptable output of a swap path based on the safe price.
+    ///
+    /// The input amount is converted hop by hop at the safe price of each pair and the
+    /// maximum deviation is then subtracted from the final amount.
+    ///
+    /// ### Arguments
+    /// * `amount_in` - The amount of the first token of the path.
+    /// * `swap_contracts` - The pair contracts of the swap path.
+    ///
+    /// ### Returns
+    /// The minimum output amount. Fails if no safe price view contract is configured.
+    fn get_safe_price_min_amount_out(
+        &self,
+        amount_in: &BigUint,
+        swap_contracts: &ManagedVec<PairContractMetadata<Self::Api>>,
+    ) -> BigUint {
+        require!(
+            !self.safe_price_view_sc_address().is_empty(),
+            ERROR_SAFE_PRICE_VIEW_NOT_SET
+        );
+
+        let safe_price_view_sc_address = self.safe_price_view_sc_address().get();
+        let mut amount = amount_in.clone();
+        for pair_contract in swap_contracts.iter() {
+            amount = self
+                .tx()
+                .to(&safe_price_view_sc_address)
+                .typed(safe_price_proxy::SafePriceViewProxy)
+                .get_safe_price_by_default_offset(
+                    &pair_contract.address,
+                    EsdtTokenPayment::new(pair_contract.first_token_id.clone(), 0, amount),
+                )
+                .returns(ReturnsResult)
+                .sync_call_readonly()
+                .amount;
+        }
+
+        amount * BigUint::from(MAX_PERCENTAGE - self.get_final_max_price_deviation_percentage())
+            / BigUint::from(MAX_PERCENTAGE)
+    }
+
+    // === Storage ===
+
+    #[storage_mapper("safe_price_view_sc_address")]
+    fn safe_price_view_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("max_price_deviation_percentage")]
+    fn max_price_deviation_percentage(&self) -> SingleValueMapper<u64>;
+
+    // === Events ===
+
+    #[event("safePriceViewScAddressSet")]
+    fn event_safe_price_view_sc_address_set(&self, #[indexed] address: &ManagedAddress);
+
+    #[event("maxPriceDeviationPercentageSet")]
+    fn event_max_price_deviation_percentage_set(
+        &self,
+        #[indexed] max_price_deviation_percentage: u64,
+    );
+}
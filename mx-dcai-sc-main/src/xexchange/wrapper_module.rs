@@ -1,12 +1,13 @@
 use multiversx_sc::imports::*;
 
-use constants::WRAPPER_SC_ADDRESS;
+use errors::ERROR_INVALID_ADDRESS;
 
 use super::{pair_module, wrapper_proxy};
 
 /// Smart Contract module for EGLD/WEGLD wrapping and unwrapping.
 ///
 /// This module provides:
+/// * An owner endpoint to set the wrapper contract address
 /// * A view to get the wrapper contract address
 /// * Methods to wrap EGLD into WEGLD
 /// * Methods to unwrap WEGLD back into EGLD
@@ -15,12 +16,29 @@ use super::{pair_module, wrapper_proxy};
 /// instead of native EGLD for technical reasons.
 #[multiversx_sc::module]
 pub trait WrapperModule: pair_module::XExchangePairModule {
+    // === Endpoints ===
+
+    /// Sets the EGLD/WEGLD wrapper smart contract address.
+    ///
+    /// Only the contract owner can call this endpoint.
+    ///
+    /// ### Arguments
+    /// * `address` - The wrapper contract address on the current network.
+    #[only_owner]
+    #[endpoint(setWrapperScAddress)]
+    fn set_wrapper_sc_address(&self, address: ManagedAddress) {
+        require!(!address.is_zero(), ERROR_INVALID_ADDRESS);
+
+        self.wrapper_sc_address().set(&address);
+        self.event_wrapper_sc_address_set(&address);
+    }
+
     // === Views ===
 
     /// Returns the EGLD/WEGLD wrapper smart contract address.
     #[view(getWrapperScAddress)]
     fn get_wrapper_sc_address(&self) -> ManagedAddress {
-        ManagedAddress::new_from_bytes(&WRAPPER_SC_ADDRESS)
+        self.wrapper_sc_address().get()
     }
 
     // === Private ===
@@ -50,4 +68,14 @@ pub trait WrapperModule: pair_module::XExchangePairModule {
             .single_esdt(&self.get_wegld_identifier(), 0u64, amount)
             .sync_call();
     }
+
+    // === Storage ===
+
+    #[storage_mapper("wrapper_sc_address")]
+    fn wrapper_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // === Events ===
+
+    #[event("wrapperScAddressSet")]
+    fn event_wrapper_sc_address_set(&self, #[indexed] address: &ManagedAddress);
 }
@@ -1,15 +1,24 @@
 //! Basic tests for DCAi Smart Contract
 //!
-//! Tests the main endpoints in the bot and strategy modules
+//! Tests the main endpoints in the bot and strategy modules, and the strategies end to end against
+//! mock xExchange contracts
 
+mod mocks;
+
+use lib::Dcai;
 use multiversx_sc_scenario::imports::*;
-use structs::TokenWithdrawn;
+use structs::{
+    BuySizingMode, CatchUpPolicy, LegacyStrategyTokenAttributes, PostTakeProfitAction,
+    StrategyConfig, StrategyDirection, StrategyTokenAttributes, Swap, SwapMinAmountOut,
+    TokenWithdrawn,
+};
 
 #[allow(dead_code)]
 #[allow(clippy::all)]
 mod dcai_proxy {
     pub use multiversx_sc::proxy_imports::*;
-    
+    use structs::SwapMinAmountOut;
+
     pub struct DcaiProxy;
 
     impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for DcaiProxy
@@ -44,8 +53,22 @@ mod dcai_proxy {
         From: TxFrom<Env>,
         Gas: TxGas<Env>,
     {
-        pub fn init(self) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
-            self.wrapped_tx.payment(NotPayable).raw_deploy().original_result()
+        pub fn init<
+            Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+            Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+            Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+            Arg3: ProxyArg<TokenIdentifier<Env::Api>>,
+            Arg4: ProxyArg<TokenIdentifier<Env::Api>>,
+        >(
+            self, xexchange_router_sc_address: Arg0, wrapper_sc_address: Arg1, safe_price_view_sc_address: Arg2, usdc_identifier: Arg3, wegld_identifier: Arg4
+        ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_deploy()
+                .argument(&xexchange_router_sc_address)
+                .argument(&wrapper_sc_address)
+                .argument(&safe_price_view_sc_address)
+                .argument(&usdc_identifier)
+                .argument(&wegld_identifier)
+                .original_result()
         }
     }
 
@@ -58,6 +81,26 @@ mod dcai_proxy {
         To: TxTo<Env>,
         Gas: TxGas<Env>,
     {
+        pub fn upgrade<
+            Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+            Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+            Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+            Arg3: ProxyArg<TokenIdentifier<Env::Api>>,
+            Arg4: ProxyArg<TokenIdentifier<Env::Api>>,
+            Arg5: ProxyArg<OptionalValue<u32>>,
+        >(
+            self, xexchange_router_sc_address: Arg0, wrapper_sc_address: Arg1, safe_price_view_sc_address: Arg2, usdc_identifier: Arg3, wegld_identifier: Arg4, legacy_dca_token_decimals: Arg5
+        ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_upgrade()
+                .argument(&xexchange_router_sc_address)
+                .argument(&wrapper_sc_address)
+                .argument(&safe_price_view_sc_address)
+                .argument(&usdc_identifier)
+                .argument(&wegld_identifier)
+                .argument(&legacy_dca_token_decimals)
+                .original_result()
+        }
+
         pub fn pause(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
             self.wrapped_tx.payment(NotPayable).raw_call("pause").original_result()
         }
@@ -70,6 +113,50 @@ mod dcai_proxy {
             self.wrapped_tx.payment(NotPayable).raw_call("isPaused").original_result()
         }
 
+        pub fn set_xexchange_router_sc_address<Arg0: ProxyArg<ManagedAddress<Env::Api>>>(
+            self, address: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("setXexchangeRouterScAddress").argument(&address).original_result()
+        }
+
+        pub fn set_safe_price_view_sc_address<Arg0: ProxyArg<ManagedAddress<Env::Api>>>(
+            self, address: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("setSafePriceViewScAddress").argument(&address).original_result()
+        }
+
+        pub fn safe_price_view_sc_address(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getSafePriceViewScAddress").original_result()
+        }
+
+        pub fn set_max_price_deviation_percentage<Arg0: ProxyArg<u64>>(
+            self, max_price_deviation_percentage: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("setMaxPriceDeviationPercentage").argument(&max_price_deviation_percentage).original_result()
+        }
+
+        pub fn max_price_deviation_percentage(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getMaxPriceDeviationPercentage").original_result()
+        }
+
+        pub fn xexchange_router_sc_address(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getXexchangeRouterScAddress").original_result()
+        }
+
+        pub fn wrapper_sc_address(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getWrapperScAddress").original_result()
+        }
+
+        pub fn set_usdc_identifier<Arg0: ProxyArg<TokenIdentifier<Env::Api>>>(
+            self, usdc_identifier: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("setUsdcIdentifier").argument(&usdc_identifier).original_result()
+        }
+
+        pub fn usdc_identifier(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getUsdcIdentifier").original_result()
+        }
+
         pub fn set_bot_address<Arg0: ProxyArg<ManagedAddress<Env::Api>>>(
             self, address: Arg0
         ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
@@ -86,28 +173,80 @@ mod dcai_proxy {
             self.wrapped_tx.payment(NotPayable).raw_call("isBot").argument(&address).original_result()
         }
 
-        pub fn buy<Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>>(
-            self, nonces: Arg0
+        pub fn buy<
+            Arg0: ProxyArg<u64>,
+            Arg1: ProxyArg<ManagedVec<Env::Api, SwapMinAmountOut<Env::Api>>>,
+            Arg2: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+        >(
+            self, deadline_ts_millis: Arg0, min_amounts_out: Arg1, nonces: Arg2
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("buy").argument(&deadline_ts_millis).argument(&min_amounts_out).argument(&nonces).original_result()
+        }
+
+        pub fn take_profit<
+            Arg0: ProxyArg<u64>,
+            Arg1: ProxyArg<ManagedVec<Env::Api, SwapMinAmountOut<Env::Api>>>,
+            Arg2: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+        >(
+            self, deadline_ts_millis: Arg0, min_amounts_out: Arg1, nonces: Arg2
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("takeProfit").argument(&deadline_ts_millis).argument(&min_amounts_out).argument(&nonces).original_result()
+        }
+
+        pub fn stop_loss<
+            Arg0: ProxyArg<u64>,
+            Arg1: ProxyArg<ManagedVec<Env::Api, SwapMinAmountOut<Env::Api>>>,
+            Arg2: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+        >(
+            self, deadline_ts_millis: Arg0, min_amounts_out: Arg1, nonces: Arg2
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("stopLoss").argument(&deadline_ts_millis).argument(&min_amounts_out).argument(&nonces).original_result()
+        }
+
+        pub fn add_allowed_dca_tokens<Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, u32>>>>(
+            self, dca_tokens: Arg0
         ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
-            self.wrapped_tx.payment(NotPayable).raw_call("buy").argument(&nonces).original_result()
+            self.wrapped_tx.payment(NotPayable).raw_call("addAllowedDcaTokens").argument(&dca_tokens).original_result()
         }
 
-        pub fn take_profit<Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>>(
-            self, nonces: Arg0
+        pub fn remove_allowed_dca_tokens<Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>>(
+            self, dca_tokens: Arg0
         ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
-            self.wrapped_tx.payment(NotPayable).raw_call("takeProfit").argument(&nonces).original_result()
+            self.wrapped_tx.payment(NotPayable).raw_call("removeAllowedDcaTokens").argument(&dca_tokens).original_result()
         }
 
-        pub fn set_dca_token<Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>>(
+        pub fn dca_token_decimals<Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>>(
             self, dca_token: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getDcaTokenDecimals").argument(&dca_token).original_result()
+        }
+
+        pub fn add_allowed_quote_tokens<Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>>>(
+            self, quote_tokens: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("addAllowedQuoteTokens").argument(&quote_tokens).original_result()
+        }
+
+        pub fn remove_allowed_quote_tokens<Arg0: ProxyArg<MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>>(
+            self, quote_tokens: Arg0
         ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
-            self.wrapped_tx.payment(NotPayable).raw_call("setToken").argument(&dca_token).original_result()
+            self.wrapped_tx.payment(NotPayable).raw_call("removeAllowedQuoteTokens").argument(&quote_tokens).original_result()
         }
 
-        pub fn set_min_amount_per_swap<Arg0: ProxyArg<BigUint<Env::Api>>>(
-            self, min_amount_per_swap: Arg0
+        pub fn set_min_initial_deposit<Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>, Arg1: ProxyArg<BigUint<Env::Api>>>(
+            self, quote_token: Arg0, min_initial_deposit: Arg1
         ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
-            self.wrapped_tx.payment(NotPayable).raw_call("setMinAmountPerSwap").argument(&min_amount_per_swap).original_result()
+            self.wrapped_tx.payment(NotPayable).raw_call("setMinInitialDeposit").argument(&quote_token).argument(&min_initial_deposit).original_result()
+        }
+
+        pub fn min_initial_deposit<Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>>(
+            self, quote_token: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getMinInitialDeposit").argument(&quote_token).original_result()
+        }
+
+        pub fn allowed_quote_tokens(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getAllowedQuoteTokens").original_result()
         }
 
         pub fn set_profit_fee_percentage<Arg0: ProxyArg<u64>>(
@@ -122,24 +261,82 @@ mod dcai_proxy {
             self.wrapped_tx.payment(NotPayable).raw_call("setCustomSlippagePercentage").argument(&custom_slippage_percentage).original_result()
         }
 
-        pub fn create_strategy<Arg0: ProxyArg<BigUint<Env::Api>>, Arg1: ProxyArg<ManagedBuffer<Env::Api>>, Arg2: ProxyArg<u64>>(
-            self, amount_per_swap: Arg0, frequency: Arg1, take_profit_percentage: Arg2
+        pub fn create_strategy<Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>, Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>, Arg2: ProxyArg<structs::StrategyConfig<Env::Api>>>(
+            self, dca_token: Arg0, quote_token: Arg1, config: Arg2
         ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
-            self.wrapped_tx.raw_call("createStrategy").argument(&amount_per_swap).argument(&frequency).argument(&take_profit_percentage).original_result()
+            self.wrapped_tx.raw_call("createStrategy").argument(&dca_token).argument(&quote_token).argument(&config).original_result()
         }
 
-        pub fn modify_strategy<Arg0: ProxyArg<BigUint<Env::Api>>, Arg1: ProxyArg<ManagedBuffer<Env::Api>>, Arg2: ProxyArg<u64>>(
-            self, amount_per_swap: Arg0, frequency: Arg1, take_profit_percentage: Arg2
+        pub fn modify_strategy<Arg0: ProxyArg<structs::StrategyConfig<Env::Api>>>(
+            self, config: Arg0
         ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
-            self.wrapped_tx.raw_call("modifyStrategy").argument(&amount_per_swap).argument(&frequency).argument(&take_profit_percentage).original_result()
+            self.wrapped_tx.raw_call("modifyStrategy").argument(&config).original_result()
+        }
+
+        pub fn set_max_slippage_percentage_cap<Arg0: ProxyArg<u64>>(
+            self, max_slippage_percentage_cap: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("setMaxSlippagePercentageCap").argument(&max_slippage_percentage_cap).original_result()
+        }
+
+        pub fn add_allowed_frequencies<Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, u64>>>>(
+            self, frequencies: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("addAllowedFrequencies").argument(&frequencies).original_result()
+        }
+
+        pub fn remove_allowed_frequencies<Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>>(
+            self, frequencies: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("removeAllowedFrequencies").argument(&frequencies).original_result()
+        }
+
+        pub fn set_frequency_duration_bounds<Arg0: ProxyArg<u64>, Arg1: ProxyArg<u64>>(
+            self, min_duration_in_millis: Arg0, max_duration_in_millis: Arg1
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+            self.wrapped_tx.payment(NotPayable).raw_call("setFrequencyDurationBounds").argument(&min_duration_in_millis).argument(&max_duration_in_millis).original_result()
+        }
+
+        pub fn frequency_duration_bounds(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getFrequencyDurationBounds").original_result()
+        }
+
+        pub fn max_slippage_percentage_cap(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getMaxSlippagePercentageCap").original_result()
         }
 
         pub fn delete_strategy(self) -> TxTypedCall<Env, From, To, (), Gas, ()> {
             self.wrapped_tx.raw_call("deleteStrategy").original_result()
         }
 
-        pub fn deposit(self) -> TxTypedCall<Env, From, To, (), Gas, ()> {
-            self.wrapped_tx.raw_call("deposit").original_result()
+        pub fn pause_strategy(self) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+            self.wrapped_tx.raw_call("pauseStrategy").original_result()
+        }
+
+        pub fn resume_strategy(self) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+            self.wrapped_tx.raw_call("resumeStrategy").original_result()
+        }
+
+        pub fn deposit<Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>>(
+            self, min_amount_out: Arg0
+        ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+            self.wrapped_tx.raw_call("deposit").argument(&min_amount_out).original_result()
+        }
+
+        pub fn import_dca_token<Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>>(
+            self, declared_cost_basis: Arg0
+        ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+            self.wrapped_tx.raw_call("importDcaToken").argument(&declared_cost_basis).original_result()
+        }
+
+        pub fn deposit_for<Arg0: ProxyArg<u64>>(self, nonce: Arg0) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+            self.wrapped_tx.raw_call("depositFor").argument(&nonce).original_result()
+        }
+
+        pub fn withdraw_all<Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>>(
+            self, recipient: Arg0
+        ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+            self.wrapped_tx.raw_call("withdrawAll").argument(&recipient).original_result()
         }
 
         pub fn withdraw<Arg0: ProxyArg<BigUint<Env::Api>>, Arg1: ProxyArg<structs::TokenWithdrawn>>(
@@ -148,26 +345,50 @@ mod dcai_proxy {
             self.wrapped_tx.raw_call("withdraw").argument(&amount).argument(&token_withdrawn).original_result()
         }
 
-        pub fn get_all_strategies(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue11<u64, BigUint<Env::Api>, ManagedBuffer<Env::Api>, u64, u64, BigUint<Env::Api>, BigUint<Env::Api>, u64, bool, ManagedVec<Env::Api, structs::Swap<Env::Api>>, ManagedVec<Env::Api, structs::Swap<Env::Api>>>>> {
+        pub fn get_all_strategies(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, structs::StrategyTokenAttributesAsType<Env::Api>>> {
             self.wrapped_tx.payment(NotPayable).raw_call("getAllStrategies").original_result()
         }
 
         pub fn get_all_strategies_only_nonces(self) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
             self.wrapped_tx.payment(NotPayable).raw_call("getAllStrategiesOnlyNonces").original_result()
         }
+
+        pub fn get_strategy_token_attributes<Arg0: ProxyArg<u64>>(
+            self, nonce: Arg0
+        ) -> TxTypedCall<Env, From, To, NotPayable, Gas, structs::StrategyTokenAttributesAsType<Env::Api>> {
+            self.wrapped_tx.payment(NotPayable).raw_call("getStrategyTokenAttributes").argument(&nonce).original_result()
+        }
     }
 }
 
 const DCAI_PATH: MxscPath = MxscPath::new("output/lib.mxsc.json");
+const ROUTER_MOCK_PATH: MxscPath = MxscPath::new("mocks/router-mock.mxsc.json");
+const PAIR_MOCK_PATH: MxscPath = MxscPath::new("mocks/pair-mock.mxsc.json");
+const SAFE_PRICE_MOCK_PATH: MxscPath = MxscPath::new("mocks/safe-price-mock.mxsc.json");
+const WRAPPER_MOCK_PATH: MxscPath = MxscPath::new("mocks/wrapper-mock.mxsc.json");
 
 const OWNER: TestAddress = TestAddress::new("owner");
 const ADMIN: TestAddress = TestAddress::new("admin");
 const BOT: TestAddress = TestAddress::new("bot");
 const USER: TestAddress = TestAddress::new("user");
 const DCAI_SC: TestSCAddress = TestSCAddress::new("dcai");
+const ROUTER_SC: TestSCAddress = TestSCAddress::new("router");
+const WRAPPER_SC: TestSCAddress = TestSCAddress::new("wrapper");
+const SAFE_PRICE_SC: TestSCAddress = TestSCAddress::new("safe-price");
+const MEX_USDC_PAIR_SC: TestSCAddress = TestSCAddress::new("mex-usdc-pair");
+const MEX_WEGLD_PAIR_SC: TestSCAddress = TestSCAddress::new("mex-wegld-pair");
+const WEGLD_USDC_PAIR_SC: TestSCAddress = TestSCAddress::new("wegld-usdc-pair");
+const LIQUIDITY: TestAddress = TestAddress::new("liquidity");
 
 const USDC_ID: TestTokenIdentifier = TestTokenIdentifier::new("USDC-350c4e");
 const WEGLD_ID: TestTokenIdentifier = TestTokenIdentifier::new("WEGLD-a28c59");
+const MEX_ID: TestTokenIdentifier = TestTokenIdentifier::new("MEX-455c57");
+const STRATEGY_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("DCAIMEX-123456");
+
+const START_TS_MILLIS: u64 = 1_000_000_000_000;
+const DAY_IN_MILLIS: u64 = 86_400_000;
+// One USDC, at 6 decimals
+const USDC: u64 = 1_000_000;
 
 fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
@@ -177,20 +398,33 @@ fn world() -> ScenarioWorld {
 
 fn setup() -> ScenarioWorld {
     let mut world = world();
-    
+
     world.current_block().block_timestamp_seconds(1_000_000_000);
 
-    world.account(OWNER).nonce(1).balance(100_000_000_000_000_000_000u128);
-    world.account(ADMIN).nonce(1).balance(10_000_000_000_000_000_000u128);
-    world.account(BOT).nonce(1).balance(10_000_000_000_000_000_000u128);
-    world.account(USER).nonce(1).balance(10_000_000_000_000_000_000u128)
+    world
+        .account(OWNER)
+        .nonce(1)
+        .balance(100_000_000_000_000_000_000u128);
+    world
+        .account(ADMIN)
+        .nonce(1)
+        .balance(10_000_000_000_000_000_000u128);
+    world
+        .account(BOT)
+        .nonce(1)
+        .balance(10_000_000_000_000_000_000u128);
+    world
+        .account(USER)
+        .nonce(1)
+        .balance(10_000_000_000_000_000_000u128)
         .esdt_balance(USDC_ID, 10_000_000_000u64);
 
     // Deploy contract
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
         .typed(dcai_proxy::DcaiProxy)
-        .init()
+        .init(ROUTER_SC, WRAPPER_SC, SAFE_PRICE_SC, USDC_ID, WEGLD_ID)
         .code(DCAI_PATH)
         .new_address(DCAI_SC)
         .run();
@@ -198,318 +432,2959 @@ fn setup() -> ScenarioWorld {
     world
 }
 
-// ============================================
-// Bot Module Tests
-// ============================================
+/// Returns the given amount of whole MEX, at 18 decimals.
+fn mex(amount: u64) -> BigUint<StaticApi> {
+    BigUint::from(amount) * BigUint::from(10u64).pow(18)
+}
 
-#[test]
-fn test_set_bot_address() {
-    let mut world = setup();
+/// Returns the given amount of whole USDC.
+fn usdc(amount: u64) -> BigUint<StaticApi> {
+    BigUint::from(amount * USDC)
+}
+
+/// Sets up the contract with its strategy token, next to mock xExchange router, wrapper and safe price
+/// contracts and a MEX/USDC pair priced at 1 USDC per MEX. USDC is the quote token, MEX the DCA token
+/// and strategies can buy daily.
+///
+/// The debugger cannot issue the dynamic strategy token through `setup`, so the contract account is created
+/// holding the token roles and identifier, and initialized through a whitebox call.
+fn setup_with_dex() -> ScenarioWorld {
+    let mut world = world();
+    setup_dex(&mut world);
+
+    world
+        .account(DCAI_SC)
+        .nonce(1)
+        .code(DCAI_PATH)
+        .owner(OWNER)
+        .esdt_roles(
+            STRATEGY_TOKEN_ID,
+            vec![
+                "ESDTRoleNFTCreate".to_string(),
+                "ESDTRoleNFTBurn".to_string(),
+                "ESDTRoleNFTUpdateAttributes".to_string(),
+            ],
+        )
+        .storage_mandos("str:strategy_token", "str:DCAIMEX-123456");
+    init_dcai(&mut world);
 
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .set_bot_address(BOT)
+        .add_allowed_quote_tokens(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            usdc(1),
+        ))]))
         .run();
 
-    world.query()
+    world
+        .tx()
+        .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .bot_address()
-        .returns(ExpectValue(BOT.to_address()))
+        .add_allowed_frequencies(MultiValueVec::from(vec![MultiValue2::from((
+            ManagedBuffer::from("Daily"),
+            DAY_IN_MILLIS,
+        ))]))
         .run();
-}
 
-#[test]
-fn test_is_bot_returns_true_for_bot() {
-    let mut world = setup();
-
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .set_bot_address(BOT)
+        .add_allowed_dca_tokens(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+            18u32,
+        ))]))
         .run();
 
-    world.query()
+    world
+        .tx()
+        .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .is_bot(BOT)
-        .returns(ExpectValue(true))
+        .set_bot_address(BOT)
         .run();
+
+    world
 }
 
-#[test]
-fn test_is_bot_returns_true_for_admin() {
-    let mut world = setup();
+/// Funds the test accounts and deploys the mock xExchange contracts, pricing MEX at 1 USDC.
+fn setup_dex(world: &mut ScenarioWorld) {
+    world.register_contract(ROUTER_MOCK_PATH, mocks::router_mock::ContractBuilder);
+    world.register_contract(PAIR_MOCK_PATH, mocks::pair_mock::ContractBuilder);
+    world.register_contract(
+        SAFE_PRICE_MOCK_PATH,
+        mocks::safe_price_mock::ContractBuilder,
+    );
+    world.register_contract(WRAPPER_MOCK_PATH, mocks::wrapper_mock::ContractBuilder);
 
-    // Set bot address first to initialize the storage
-    world.tx()
+    set_block_timestamp_millis(world, START_TS_MILLIS);
+
+    world
+        .account(OWNER)
+        .nonce(1)
+        .balance(100_000_000_000_000_000_000u128);
+    world
+        .account(BOT)
+        .nonce(1)
+        .balance(10_000_000_000_000_000_000u128);
+    world
+        .account(USER)
+        .nonce(1)
+        .balance(10_000_000_000_000_000_000u128)
+        .esdt_balance(USDC_ID, usdc(10_000))
+        .esdt_balance(MEX_ID, mex(10_000))
+        .esdt_balance(WEGLD_ID, mex(1_000));
+    world
+        .account(LIQUIDITY)
+        .nonce(1)
+        .balance(1_000_000_000_000_000_000_000u128)
+        .esdt_balance(USDC_ID, usdc(1_000_000))
+        .esdt_balance(MEX_ID, mex(1_000_000))
+        .esdt_balance(WEGLD_ID, mex(2_000_000));
+
+    world
+        .tx()
         .from(OWNER)
-        .to(DCAI_SC)
-        .typed(dcai_proxy::DcaiProxy)
-        .set_bot_address(BOT)
+        .raw_deploy()
+        .code(ROUTER_MOCK_PATH)
+        .new_address(ROUTER_SC)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(SAFE_PRICE_MOCK_PATH)
+        .new_address(SAFE_PRICE_SC)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(WRAPPER_MOCK_PATH)
+        .argument(&WEGLD_ID)
+        .new_address(WRAPPER_SC)
+        .run();
+    world
+        .tx()
+        .from(LIQUIDITY)
+        .to(WRAPPER_SC)
+        .raw_call("addLiquidity")
+        .single_esdt(&WEGLD_ID.to_token_identifier(), 0, &mex(1_000_000))
+        .run();
+    world
+        .tx()
+        .from(LIQUIDITY)
+        .to(WRAPPER_SC)
+        .raw_call("addLiquidity")
+        .egld(mex(1_000))
         .run();
+    deploy_pair(world, MEX_USDC_PAIR_SC, MEX_ID, USDC_ID);
+    set_mex_price(world, USDC);
+}
 
-    // Owner is admin by default, so should be recognized as bot too
-    world.query()
+/// Initializes the DCAi contract with the mock xExchange contracts.
+fn init_dcai(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
         .to(DCAI_SC)
-        .typed(dcai_proxy::DcaiProxy)
-        .is_bot(OWNER)
-        .returns(ExpectValue(true))
-        .run();
+        .whitebox(lib::contract_obj, |sc| {
+            sc.init(
+                ROUTER_SC.to_managed_address(),
+                WRAPPER_SC.to_managed_address(),
+                SAFE_PRICE_SC.to_managed_address(),
+                USDC_ID.to_token_identifier(),
+                WEGLD_ID.to_token_identifier(),
+            );
+        });
 }
 
-#[test]
-fn test_is_bot_returns_false_for_user() {
-    let mut world = setup();
+/// Deploys a mock pair of the two tokens, registers it with the router and funds it for swaps.
+fn deploy_pair(
+    world: &mut ScenarioWorld,
+    pair: TestSCAddress,
+    first_token: TestTokenIdentifier,
+    second_token: TestTokenIdentifier,
+) {
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(PAIR_MOCK_PATH)
+        .argument(&first_token)
+        .argument(&second_token)
+        .argument(&1u64)
+        .argument(&1u64)
+        .new_address(pair)
+        .run();
 
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
-        .to(DCAI_SC)
-        .typed(dcai_proxy::DcaiProxy)
-        .set_bot_address(BOT)
+        .to(ROUTER_SC)
+        .raw_call("setPair")
+        .argument(&first_token)
+        .argument(&second_token)
+        .argument(&pair)
         .run();
 
-    world.query()
-        .to(DCAI_SC)
-        .typed(dcai_proxy::DcaiProxy)
-        .is_bot(USER)
-        .returns(ExpectValue(false))
+    for token in [first_token, second_token] {
+        let liquidity = if token == USDC_ID {
+            usdc(100_000)
+        } else {
+            mex(100_000)
+        };
+        world
+            .tx()
+            .from(LIQUIDITY)
+            .to(pair)
+            .raw_call("addLiquidity")
+            .single_esdt(&token.to_token_identifier(), 0, &liquidity)
+            .run();
+    }
+}
+
+/// Sets the reserves, and so the price, of a mock pair.
+fn set_pair_reserves(
+    world: &mut ScenarioWorld,
+    pair: TestSCAddress,
+    first_reserve: BigUint<StaticApi>,
+    second_reserve: BigUint<StaticApi>,
+) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(pair)
+        .raw_call("setReserves")
+        .argument(&first_reserve)
+        .argument(&second_reserve)
         .run();
 }
 
-#[test]
-fn test_set_bot_address_non_admin_fails() {
-    let mut world = setup();
+/// Sets the pool price of one whole MEX, in USDC units.
+fn set_mex_price(world: &mut ScenarioWorld, usdc_per_mex: u64) {
+    set_pair_reserves(world, MEX_USDC_PAIR_SC, mex(1), BigUint::from(usdc_per_mex));
+}
 
-    world.tx()
-        .from(USER)
-        .to(DCAI_SC)
-        .typed(dcai_proxy::DcaiProxy)
-        .set_bot_address(BOT)
-        .with_result(ExpectError(4, "Only admin allowed"))
+/// Sets the safe price the mock safe price view quotes, as a percentage of the pool price.
+fn set_safe_price_percentage(world: &mut ScenarioWorld, price_percentage: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SAFE_PRICE_SC)
+        .raw_call("setPricePercentage")
+        .argument(&price_percentage)
         .run();
 }
 
-#[test]
-fn test_buy_non_bot_fails() {
-    let mut world = setup();
+/// Moves the block time, in milliseconds.
+fn set_block_timestamp_millis(world: &mut ScenarioWorld, ts_millis: u64) {
+    world
+        .current_block()
+        .block_timestamp_seconds(ts_millis / 1_000)
+        .block_timestamp_millis(ts_millis);
+}
 
-    world.tx()
-        .from(OWNER)
+/// Creates a MEX strategy funded with the given USDC deposit and returns its nonce.
+fn create_mex_strategy(
+    world: &mut ScenarioWorld,
+    config: StrategyConfig<StaticApi>,
+    deposit: BigUint<StaticApi>,
+) -> u64 {
+    create_strategy(world, USDC_ID, config, deposit)
+}
+
+/// Creates a MEX strategy funded with the given deposit of its quote token and returns its nonce.
+fn create_strategy(
+    world: &mut ScenarioWorld,
+    quote_token: TestTokenIdentifier,
+    config: StrategyConfig<StaticApi>,
+    deposit: BigUint<StaticApi>,
+) -> u64 {
+    world
+        .tx()
+        .from(USER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .set_bot_address(BOT)
+        .create_strategy(
+            EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+            EgldOrEsdtTokenIdentifier::esdt(quote_token.to_token_identifier()),
+            config,
+        )
+        .single_esdt(&quote_token.to_token_identifier(), 0, &deposit)
         .run();
 
-    world.tx()
-        .from(USER)
+    let nonces = world
+        .query()
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .buy(MultiValueVec::from(vec![1u64]))
-        .with_result(ExpectError(4, "Only bot allowed"))
+        .get_all_strategies_only_nonces()
+        .returns(ReturnsResult)
         .run();
+    nonces.into_iter().max().unwrap()
 }
 
-#[test]
-fn test_take_profit_non_bot_fails() {
-    let mut world = setup();
+/// Returns the attributes of a strategy.
+fn strategy_attributes(
+    world: &mut ScenarioWorld,
+    nonce: u64,
+) -> StrategyTokenAttributes<StaticApi> {
+    let (_, attributes, _, _, _) = world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .get_strategy_token_attributes(nonce)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    attributes
+}
 
-    world.tx()
-        .from(OWNER)
+/// Returns whether a strategy's max buy price condition currently holds.
+fn strategy_price_condition_met(world: &mut ScenarioWorld, nonce: u64) -> bool {
+    let (_, _, _, _, price_condition_met) = world
+        .query()
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .set_bot_address(BOT)
-        .run();
+        .get_strategy_token_attributes(nonce)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    price_condition_met
+}
 
-    world.tx()
-        .from(USER)
+/// Sets the contract wide profit fee percentage.
+fn set_profit_fee_percentage(world: &mut ScenarioWorld, profit_fee_percentage: u64) {
+    world
+        .tx()
+        .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .take_profit(MultiValueVec::from(vec![1u64]))
-        .with_result(ExpectError(4, "Only bot allowed"))
+        .set_profit_fee_percentage(profit_fee_percentage)
         .run();
 }
 
-// ============================================
-// Strategy Module Tests
-// ============================================
+/// A daily scheduled sell of 50 MEX into USDC.
+fn scheduled_sell_config() -> StrategyConfig<StaticApi> {
+    StrategyConfig {
+        direction: StrategyDirection::Sell,
+        dca_token_amount_per_swap: mex(50),
+        ..fixed_buy_config()
+    }
+}
 
-#[test]
-fn test_create_strategy_requires_setup() {
-    let mut world = setup();
+/// A fixed daily 100 USDC buying strategy, without take profit or end conditions.
+fn fixed_buy_config() -> StrategyConfig<StaticApi> {
+    StrategyConfig {
+        amount_per_swap: usdc(100),
+        frequency_in_millis: DAY_IN_MILLIS,
+        ..Default::default()
+    }
+}
 
-    // Without setup complete, creating a strategy should fail
-    world.tx()
-        .from(USER)
+/// Calls `buy` as the bot for the given strategies, without a deadline or minimum outputs.
+fn bot_buy(world: &mut ScenarioWorld, nonces: Vec<u64>) {
+    world
+        .tx()
+        .from(BOT)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .create_strategy(BigUint::from(100_000_000u64), ManagedBuffer::from(b"daily"), 1000u64)
-        .with_result(ExpectError(4, "DCA strategy not set"))
+        .buy(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(nonces),
+        )
         .run();
 }
 
-#[test]
-fn test_modify_strategy_requires_setup() {
-    let mut world = setup();
-
-    // Without setup complete, modifying a strategy should fail
-    world.tx()
-        .from(USER)
+/// Calls `stopLoss` as the bot for the given strategies, without a deadline or minimum outputs.
+fn bot_stop_loss(world: &mut ScenarioWorld, nonces: Vec<u64>) {
+    world
+        .tx()
+        .from(BOT)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .modify_strategy(BigUint::from(100_000_000u64), ManagedBuffer::from(b"daily"), 1000u64)
-        .with_result(ExpectError(4, "DCA strategy not set"))
+        .stop_loss(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(nonces),
+        )
         .run();
 }
 
-#[test]
-fn test_delete_strategy_requires_setup() {
-    let mut world = setup();
-
-    // Without setup complete, deleting a strategy should fail
-    world.tx()
-        .from(USER)
+/// Calls `takeProfit` as the bot for the given strategies, without a deadline or minimum outputs.
+fn bot_take_profit(world: &mut ScenarioWorld, nonces: Vec<u64>) {
+    world
+        .tx()
+        .from(BOT)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .delete_strategy()
-        .with_result(ExpectError(4, "DCA strategy not set"))
+        .take_profit(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(nonces),
+        )
         .run();
 }
 
-#[test]
-fn test_deposit_requires_setup() {
-    let mut world = setup();
-
-    // Without setup complete, depositing should fail
-    world.tx()
+/// Imports MEX into a strategy, at a declared cost basis or, when `None`, at the pool value.
+fn import_mex(
+    world: &mut ScenarioWorld,
+    nonce: u64,
+    amount: BigUint<StaticApi>,
+    declared_cost_basis: Option<BigUint<StaticApi>>,
+) {
+    let mut payments = ManagedVec::<StaticApi, EsdtTokenPayment<StaticApi>>::new();
+    payments.push(EsdtTokenPayment::new(
+        STRATEGY_TOKEN_ID.to_token_identifier(),
+        nonce,
+        BigUint::from(1u64),
+    ));
+    payments.push(EsdtTokenPayment::new(
+        MEX_ID.to_token_identifier(),
+        0,
+        amount,
+    ));
+    world
+        .tx()
         .from(USER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .deposit()
-        .with_result(ExpectError(4, "DCA strategy not set"))
+        .import_dca_token(OptionalValue::from(declared_cost_basis))
+        .payment(payments)
         .run();
 }
 
-#[test]
-fn test_withdraw_requires_setup() {
-    let mut world = setup();
+/// Returns the payments of a deposit into a strategy: its NFT and the deposited token.
+fn deposit_payments(
+    nonce: u64,
+    token: EgldOrEsdtTokenIdentifier<StaticApi>,
+    amount: BigUint<StaticApi>,
+) -> ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>> {
+    let mut payments = ManagedVec::new();
+    payments.push(EgldOrEsdtTokenPayment::new(
+        EgldOrEsdtTokenIdentifier::esdt(STRATEGY_TOKEN_ID.to_token_identifier()),
+        nonce,
+        BigUint::from(1u64),
+    ));
+    payments.push(EgldOrEsdtTokenPayment::new(token, 0, amount));
+    payments
+}
 
-    // Without setup complete, withdrawing should fail
-    world.tx()
+/// Pauses or resumes a strategy as its owner.
+fn set_strategy_paused(world: &mut ScenarioWorld, nonce: u64, is_paused: bool) {
+    let tx = world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy);
+    let tx = if is_paused {
+        tx.pause_strategy()
+    } else {
+        tx.resume_strategy()
+    };
+    tx.single_esdt(
+        &STRATEGY_TOKEN_ID.to_token_identifier(),
+        nonce,
+        &BigUint::from(1u64),
+    )
+    .run();
+}
+
+/// Withdraws part of a strategy's balance to its owner.
+fn user_withdraw(
+    world: &mut ScenarioWorld,
+    nonce: u64,
+    amount: BigUint<StaticApi>,
+    token_withdrawn: TokenWithdrawn,
+) {
+    world
+        .tx()
         .from(USER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .withdraw(BigUint::from(100_000_000u64), TokenWithdrawn::Usdc)
-        .with_result(ExpectError(4, "DCA strategy not set"))
+        .withdraw(amount, token_withdrawn)
+        .single_esdt(
+            &STRATEGY_TOKEN_ID.to_token_identifier(),
+            nonce,
+            &BigUint::from(1u64),
+        )
         .run();
 }
 
-// Note: get_all_strategies and get_all_strategies_only_nonces views 
-// require the strategy token to be issued via the setup endpoint,
-// which requires payment and callback processing not easily testable
-// in basic unit tests.
+fn strategy_config() -> StrategyConfig<StaticApi> {
+    StrategyConfig {
+        amount_per_swap: BigUint::from(100_000_000u64),
+        frequency_in_millis: 86_400_000u64,
+        take_profit_percentage: 1_000u64,
+        take_profit_sell_percentage: 5_000u64,
+        trailing_take_profit_percentage: 500u64,
+        post_take_profit_action: PostTakeProfitAction::Withdraw,
+        catch_up_policy: CatchUpPolicy::CatchUp,
+        max_catch_up_buys: 3u64,
+        max_buys: 12u64,
+        max_total_spend: BigUint::from(1_200_000_000u64),
+        buy_sizing_mode: BuySizingMode::PercentageOfBalance,
+        min_amount_per_swap: BigUint::from(50_000_000u64),
+        dip_threshold_percentage: 1_000u64,
+        dip_multiplier_percentage: 20_000u64,
+        balance_percentage_per_swap: 1_000u64,
+        max_buy_price: BigUint::from(40_000_000u64),
+        ..Default::default()
+    }
+}
 
 // ============================================
-// Setup Module Tests
+// Bot Module Tests
 // ============================================
 
 #[test]
-fn test_set_dca_token() {
+fn test_set_bot_address() {
     let mut world = setup();
 
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .set_dca_token(EgldOrEsdtTokenIdentifier::esdt(WEGLD_ID.to_token_identifier()))
+        .set_bot_address(BOT)
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .bot_address()
+        .returns(ExpectValue(BOT.to_address()))
         .run();
 }
 
 #[test]
-fn test_set_min_amount_per_swap() {
+fn test_is_bot_returns_true_for_bot() {
     let mut world = setup();
 
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .set_min_amount_per_swap(BigUint::from(10_000_000u64))
+        .set_bot_address(BOT)
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .is_bot(BOT)
+        .returns(ExpectValue(true))
         .run();
 }
 
 #[test]
-fn test_set_profit_fee_percentage() {
+fn test_is_bot_returns_true_for_admin() {
     let mut world = setup();
 
-    world.tx()
+    // Set bot address first to initialize the storage
+    world
+        .tx()
         .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .set_profit_fee_percentage(1000u64)
+        .set_bot_address(BOT)
+        .run();
+
+    // Owner is admin by default, so should be recognized as bot too
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .is_bot(OWNER)
+        .returns(ExpectValue(true))
         .run();
 }
 
 #[test]
-fn test_set_custom_slippage_percentage() {
+fn test_is_bot_returns_false_for_user() {
     let mut world = setup();
 
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .set_custom_slippage_percentage(100u64)
+        .set_bot_address(BOT)
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .is_bot(USER)
+        .returns(ExpectValue(false))
+        .run();
+}
+
+#[test]
+fn test_set_bot_address_non_admin_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_bot_address(BOT)
+        .with_result(ExpectError(4, "Only admin allowed"))
+        .run();
+}
+
+#[test]
+fn test_buy_non_bot_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_bot_address(BOT)
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![1u64]),
+        )
+        .with_result(ExpectError(4, "Only bot allowed"))
+        .run();
+}
+
+#[test]
+fn test_take_profit_non_bot_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_bot_address(BOT)
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .take_profit(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![1u64]),
+        )
+        .with_result(ExpectError(4, "Only bot allowed"))
+        .run();
+}
+
+#[test]
+fn test_stop_loss_non_bot_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_bot_address(BOT)
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .stop_loss(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![1u64]),
+        )
+        .with_result(ExpectError(4, "Only bot allowed"))
+        .run();
+}
+
+#[test]
+fn test_buy_after_deadline_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_bot_address(BOT)
+        .run();
+
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(
+            999_999_999_999u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![1u64]),
+        )
+        .with_result(ExpectError(4, "Deadline passed"))
         .run();
 }
 
 // ============================================
-// Pause Module Tests
+// Strategy Module Tests
 // ============================================
 
 #[test]
-fn test_pause_and_unpause() {
+fn test_create_strategy_requires_setup() {
+    let mut world = setup();
+
+    // Without setup complete, creating a strategy should fail
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .create_strategy(
+            EgldOrEsdtTokenIdentifier::egld(),
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            strategy_config(),
+        )
+        .with_result(ExpectError(4, "DCA strategy not set"))
+        .run();
+}
+
+#[test]
+fn test_modify_strategy_requires_setup() {
+    let mut world = setup();
+
+    // Without setup complete, modifying a strategy should fail
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .modify_strategy(strategy_config())
+        .with_result(ExpectError(4, "DCA strategy not set"))
+        .run();
+}
+
+#[test]
+fn test_delete_strategy_requires_setup() {
+    let mut world = setup();
+
+    // Without setup complete, deleting a strategy should fail
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .delete_strategy()
+        .with_result(ExpectError(4, "DCA strategy not set"))
+        .run();
+}
+
+#[test]
+fn test_deposit_requires_setup() {
+    let mut world = setup();
+
+    // Without setup complete, depositing should fail
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit(OptionalValue::<BigUint<StaticApi>>::None)
+        .with_result(ExpectError(4, "DCA strategy not set"))
+        .run();
+}
+
+#[test]
+fn test_withdraw_requires_setup() {
+    let mut world = setup();
+
+    // Without setup complete, withdrawing should fail
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .withdraw(BigUint::from(100_000_000u64), TokenWithdrawn::QuoteToken)
+        .with_result(ExpectError(4, "DCA strategy not set"))
+        .run();
+}
+
+// Note: get_all_strategies and get_all_strategies_only_nonces views
+// require the strategy token to be issued via the setup endpoint,
+// which requires payment and callback processing not easily testable
+// in basic unit tests.
+
+// ============================================
+// Setup Module Tests
+// ============================================
+
+#[test]
+fn test_add_and_remove_allowed_dca_tokens() {
     let mut world = setup();
 
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .pause()
+        .add_allowed_dca_tokens(MultiValueVec::from(vec![
+            MultiValue2::from((EgldOrEsdtTokenIdentifier::egld(), 18u32)),
+            MultiValue2::from((
+                EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+                6u32,
+            )),
+        ]))
         .run();
 
-    world.query()
+    world
+        .query()
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .is_paused()
-        .returns(ExpectValue(true))
+        .dca_token_decimals(EgldOrEsdtTokenIdentifier::esdt(
+            USDC_ID.to_token_identifier(),
+        ))
+        .returns(ExpectValue(6u32))
         .run();
 
-    world.tx()
+    world
+        .tx()
         .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .unpause()
+        .remove_allowed_dca_tokens(MultiValueVec::from(vec![EgldOrEsdtTokenIdentifier::egld()]))
         .run();
 
-    world.query()
+    world
+        .tx()
+        .from(OWNER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .is_paused()
-        .returns(ExpectValue(false))
+        .add_allowed_dca_tokens(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::esdt(WEGLD_ID.to_token_identifier()),
+            19u32,
+        ))]))
+        .with_result(ExpectError(4, "Invalid DCA token"))
+        .run();
+
+    // A removed token keeps its decimals, a token never allowed has none
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .dca_token_decimals(EgldOrEsdtTokenIdentifier::egld())
+        .returns(ExpectValue(18u32))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .dca_token_decimals(EgldOrEsdtTokenIdentifier::esdt(
+            WEGLD_ID.to_token_identifier(),
+        ))
+        .returns(ExpectError(4, "Invalid DCA token"))
         .run();
 }
 
 #[test]
-fn test_pause_non_admin_fails() {
+fn test_add_allowed_dca_tokens_non_admin_fails() {
     let mut world = setup();
 
-    world.tx()
+    world
+        .tx()
         .from(USER)
         .to(DCAI_SC)
         .typed(dcai_proxy::DcaiProxy)
-        .pause()
+        .add_allowed_dca_tokens(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::egld(),
+            18u32,
+        ))]))
         .with_result(ExpectError(4, "Only admin allowed"))
         .run();
 }
+
+#[test]
+fn test_add_and_remove_allowed_quote_tokens() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .add_allowed_quote_tokens(MultiValueVec::from(vec![
+            MultiValue2::from((
+                EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+                BigUint::from(10_000_000u64),
+            )),
+            MultiValue2::from((
+                EgldOrEsdtTokenIdentifier::egld(),
+                BigUint::from(10_000_000_000_000_000u64),
+            )),
+        ]))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .remove_allowed_quote_tokens(MultiValueVec::from(vec![EgldOrEsdtTokenIdentifier::egld()]))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .allowed_quote_tokens()
+        .returns(ExpectValue(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            BigUint::from(10_000_000u64),
+        ))])))
+        .run();
+}
+
+#[test]
+fn test_set_min_initial_deposit() {
+    let mut world = setup();
+    let usdc = EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier());
+
+    // The quote token must be allowed first
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_min_initial_deposit(usdc.clone(), BigUint::from(50_000_000u64))
+        .with_result(ExpectError(4, "Invalid quote token"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .add_allowed_quote_tokens(MultiValueVec::from(vec![MultiValue2::from((
+            usdc.clone(),
+            BigUint::from(10_000_000u64),
+        ))]))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_min_initial_deposit(usdc.clone(), BigUint::from(50_000_000u64))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .min_initial_deposit(usdc)
+        .returns(ExpectValue(BigUint::from(50_000_000u64)))
+        .run();
+}
+
+#[test]
+fn test_set_min_initial_deposit_non_admin_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_min_initial_deposit(
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            BigUint::from(50_000_000u64),
+        )
+        .with_result(ExpectError(4, "Only admin allowed"))
+        .run();
+}
+
+#[test]
+fn test_add_allowed_quote_tokens_non_admin_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .add_allowed_quote_tokens(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::egld(),
+            BigUint::from(10_000_000_000_000_000u64),
+        ))]))
+        .with_result(ExpectError(4, "Only admin allowed"))
+        .run();
+}
+
+#[test]
+fn test_set_profit_fee_percentage() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_profit_fee_percentage(1000u64)
+        .run();
+}
+
+#[test]
+fn test_set_custom_slippage_percentage() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_custom_slippage_percentage(100u64)
+        .run();
+}
+
+#[test]
+fn test_set_max_slippage_percentage_cap() {
+    let mut world = setup();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .max_slippage_percentage_cap()
+        .returns(ExpectValue(500u64))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_max_slippage_percentage_cap(1_000u64)
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .max_slippage_percentage_cap()
+        .returns(ExpectValue(1_000u64))
+        .run();
+}
+
+#[test]
+fn test_set_max_slippage_percentage_cap_non_admin_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_max_slippage_percentage_cap(1_000u64)
+        .with_result(ExpectError(4, "Only admin allowed"))
+        .run();
+}
+
+#[test]
+fn test_set_frequency_duration_bounds() {
+    let mut world = setup();
+
+    // Without bounds or named presets, no duration is allowed
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .frequency_duration_bounds()
+        .returns(ExpectValue(MultiValue2::from((0u64, 0u64))))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_frequency_duration_bounds(3_600_000u64, 2_592_000_000u64)
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .frequency_duration_bounds()
+        .returns(ExpectValue(MultiValue2::from((
+            3_600_000u64,
+            2_592_000_000u64,
+        ))))
+        .run();
+
+    // Min above max is rejected
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_frequency_duration_bounds(86_400_000u64, 3_600_000u64)
+        .with_result(ExpectError(4, "Invalid frequency duration bounds"))
+        .run();
+}
+
+#[test]
+fn test_remove_last_frequency_preset_requires_bounds() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .add_allowed_frequencies(MultiValueVec::from(vec![
+            MultiValue2::from((ManagedBuffer::from("Daily"), 86_400_000u64)),
+            MultiValue2::from((ManagedBuffer::from("Weekly"), 604_800_000u64)),
+        ]))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .remove_allowed_frequencies(MultiValueVec::from(vec![ManagedBuffer::from("Daily")]))
+        .run();
+
+    // Without bounds, the last preset defines the range of durations
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .remove_allowed_frequencies(MultiValueVec::from(vec![ManagedBuffer::from("Weekly")]))
+        .with_result(ExpectError(
+            4,
+            "Cannot remove the last frequency preset without frequency duration bounds",
+        ))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_frequency_duration_bounds(3_600_000u64, 2_592_000_000u64)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .remove_allowed_frequencies(MultiValueVec::from(vec![ManagedBuffer::from("Weekly")]))
+        .run();
+}
+
+#[test]
+fn test_set_frequency_duration_bounds_non_admin_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_frequency_duration_bounds(3_600_000u64, 2_592_000_000u64)
+        .with_result(ExpectError(4, "Only admin allowed"))
+        .run();
+}
+
+// ============================================
+// Pause Module Tests
+// ============================================
+
+#[test]
+fn test_pause_and_unpause() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .pause()
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .is_paused()
+        .returns(ExpectValue(true))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .unpause()
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .is_paused()
+        .returns(ExpectValue(false))
+        .run();
+}
+
+#[test]
+fn test_pause_non_admin_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .pause()
+        .with_result(ExpectError(4, "Only admin allowed"))
+        .run();
+}
+
+// ============================================
+// xExchange Module Tests
+// ============================================
+
+#[test]
+fn test_init_sets_xexchange_config() {
+    let mut world = setup();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .xexchange_router_sc_address()
+        .returns(ExpectValue(ROUTER_SC.to_address()))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .wrapper_sc_address()
+        .returns(ExpectValue(WRAPPER_SC.to_address()))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .safe_price_view_sc_address()
+        .returns(ExpectValue(OptionalValue::Some(SAFE_PRICE_SC.to_address())))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .usdc_identifier()
+        .returns(ExpectValue(USDC_ID.to_token_identifier()))
+        .run();
+}
+
+#[test]
+fn test_upgrade_sets_xexchange_config() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .upgrade(
+            WRAPPER_SC,
+            ROUTER_SC,
+            OWNER,
+            WEGLD_ID,
+            USDC_ID,
+            OptionalValue::<u32>::None,
+        )
+        .code(DCAI_PATH)
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .xexchange_router_sc_address()
+        .returns(ExpectValue(WRAPPER_SC.to_address()))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .wrapper_sc_address()
+        .returns(ExpectValue(ROUTER_SC.to_address()))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .safe_price_view_sc_address()
+        .returns(ExpectValue(OptionalValue::Some(OWNER.to_address())))
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .usdc_identifier()
+        .returns(ExpectValue(WEGLD_ID.to_token_identifier()))
+        .run();
+}
+
+#[test]
+fn test_set_xexchange_router_sc_address() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_xexchange_router_sc_address(WRAPPER_SC)
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .xexchange_router_sc_address()
+        .returns(ExpectValue(WRAPPER_SC.to_address()))
+        .run();
+}
+
+#[test]
+fn test_set_usdc_identifier_non_owner_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(ADMIN)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_usdc_identifier(WEGLD_ID)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn test_set_max_price_deviation_percentage() {
+    let mut world = setup();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .max_price_deviation_percentage()
+        .returns(ExpectValue(300u64))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_max_price_deviation_percentage(100u64)
+        .run();
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .max_price_deviation_percentage()
+        .returns(ExpectValue(100u64))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_max_price_deviation_percentage(10_000u64)
+        .with_result(ExpectError(4, "Invalid percentage"))
+        .run();
+}
+
+#[test]
+fn test_set_safe_price_view_sc_address_non_owner_fails() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(ADMIN)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_safe_price_view_sc_address(ROUTER_SC)
+        .with_result(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+// ============================================
+// Scenario Tests
+// ============================================
+
+#[test]
+fn test_buy_swaps_through_router() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(900));
+    assert_eq!(attributes.dca_token_balance, mex(100));
+    assert_eq!(attributes.scheduled_buys, 1);
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + DAY_IN_MILLIS
+    );
+    world
+        .check_account(DCAI_SC)
+        .esdt_balance(USDC_ID, usdc(900))
+        .esdt_balance(MEX_ID, mex(100));
+}
+
+#[test]
+fn test_buy_reverts_when_price_deviates_from_safe_price() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+
+    // The safe price puts MEX at 0.9 USDC, so the swap returns 11% fewer MEX than it should
+    set_safe_price_percentage(&mut world, 11_100);
+
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![nonce]),
+        )
+        .with_result(ExpectError(
+            4,
+            "Swap price deviates too much from safe price",
+        ))
+        .run();
+
+    // Within the 3% default max price deviation
+    set_safe_price_percentage(&mut world, 10_300);
+    bot_buy(&mut world, vec![nonce]);
+
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).dca_token_balance,
+        mex(100)
+    );
+}
+
+#[test]
+fn test_buy_reverts_below_min_amounts_out() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+
+    let mut min_amounts_out = ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new();
+    min_amounts_out.push(SwapMinAmountOut {
+        token_in: EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+        token_out: EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+        amount: mex(101),
+    });
+
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(0u64, min_amounts_out, MultiValueVec::from(vec![nonce]))
+        .with_result(ExpectError(4, "Swap output below minimum amount out"))
+        .run();
+
+    let mut min_amounts_out = ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new();
+    min_amounts_out.push(SwapMinAmountOut {
+        token_in: EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+        token_out: EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+        amount: mex(100),
+    });
+
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(0u64, min_amounts_out, MultiValueVec::from(vec![nonce]))
+        .run();
+
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).dca_token_balance,
+        mex(100)
+    );
+}
+
+#[test]
+fn test_catch_up_buys_once_per_missed_interval() {
+    let mut world = setup_with_dex();
+    let config = StrategyConfig {
+        catch_up_policy: CatchUpPolicy::CatchUp,
+        max_catch_up_buys: 3,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config.clone(), usdc(1_000));
+    let short_nonce = create_mex_strategy(&mut world, config, usdc(250));
+
+    // Ten days late, capped at the max catch up buys, and at the quote token balance
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + 10 * DAY_IN_MILLIS + 1);
+    bot_buy(&mut world, vec![nonce, short_nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.scheduled_buys, 3);
+    assert_eq!(attributes.quote_token_balance, usdc(700));
+    assert_eq!(attributes.dca_token_balance, mex(300));
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + 11 * DAY_IN_MILLIS
+    );
+
+    let attributes = strategy_attributes(&mut world, short_nonce);
+    assert_eq!(attributes.scheduled_buys, 2);
+    assert_eq!(attributes.quote_token_balance, usdc(50));
+    assert_eq!(attributes.dca_token_balance, mex(200));
+}
+
+#[test]
+fn test_late_buy_keeps_schedule_anchored() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).next_buy_ts_millis,
+        START_TS_MILLIS
+    );
+
+    // Two and a half days late, the skip policy buys once and the next buy stays on the daily schedule
+    set_block_timestamp_millis(
+        &mut world,
+        START_TS_MILLIS + 2 * DAY_IN_MILLIS + DAY_IN_MILLIS / 2,
+    );
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.scheduled_buys, 1);
+    assert_eq!(attributes.quote_token_balance, usdc(900));
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + 3 * DAY_IN_MILLIS
+    );
+
+    // Not due again before then
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + 3 * DAY_IN_MILLIS - 1);
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![nonce]),
+        )
+        .with_result(ExpectError(4, "No valid strategies to execute"))
+        .run();
+
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + 3 * DAY_IN_MILLIS);
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.scheduled_buys, 2);
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + 4 * DAY_IN_MILLIS
+    );
+}
+
+#[test]
+fn test_dca_token_withdrawal_removes_its_share_of_the_cost_basis() {
+    let mut world = setup_with_dex();
+    let config = StrategyConfig {
+        stop_loss_percentage: 2_000,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(1_000));
+
+    // 100 MEX at 1 USDC, then 50 MEX at 2 USDC, for a cost basis of 200 USDC
+    bot_buy(&mut world, vec![nonce]);
+    set_mex_price(&mut world, 2 * USDC);
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    bot_buy(&mut world, vec![nonce]);
+
+    // The 50 MEX left keep a third of the cost basis, so at 2 USDC they are in profit
+    user_withdraw(&mut world, nonce, mex(100), TokenWithdrawn::DcaToken);
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).dca_token_balance,
+        mex(50)
+    );
+    world.check_account(USER).esdt_balance(MEX_ID, mex(10_100));
+
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .stop_loss(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![nonce]),
+        )
+        .with_result(ExpectError(4, "No valid strategies to execute"))
+        .run();
+
+    // Back at 1 USDC, the 50 MEX are worth 25% less than their share of the cost basis
+    set_mex_price(&mut world, USDC);
+    bot_stop_loss(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, 0u64);
+    assert_eq!(attributes.quote_token_balance, usdc(850));
+}
+
+#[test]
+fn test_buy_then_withdrawal_in_the_same_block_keeps_their_order() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    bot_buy(&mut world, vec![nonce]);
+
+    // 50 MEX at 2 USDC, then half of the 150 MEX withdrawn in the same block
+    set_mex_price(&mut world, 2 * USDC);
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    bot_buy(&mut world, vec![nonce]);
+    user_withdraw(&mut world, nonce, mex(75), TokenWithdrawn::DcaToken);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(75));
+    assert_eq!(attributes.cost_basis, usdc(100));
+}
+
+#[test]
+fn test_withdrawal_then_buy_in_the_same_block_starts_a_new_position() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    bot_buy(&mut world, vec![nonce]);
+
+    // The whole position withdrawn, then 50 MEX bought at 2 USDC in the same block
+    set_mex_price(&mut world, 2 * USDC);
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    user_withdraw(&mut world, nonce, mex(100), TokenWithdrawn::DcaToken);
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(50));
+    assert_eq!(attributes.cost_basis, usdc(100));
+}
+
+#[test]
+fn test_swap_history_keeps_the_latest_buys_and_the_totals() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(2_000));
+
+    for day in 0..12 {
+        set_block_timestamp_millis(&mut world, START_TS_MILLIS + day * DAY_IN_MILLIS);
+        bot_buy(&mut world, vec![nonce]);
+    }
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.buys.len(), 10);
+    assert_eq!(
+        attributes.buys.get(0).timestamp_millis,
+        START_TS_MILLIS + 2 * DAY_IN_MILLIS
+    );
+    assert_eq!(attributes.scheduled_buys, 12);
+    assert_eq!(attributes.total_spent, usdc(1_200));
+    assert_eq!(attributes.cost_basis, usdc(1_200));
+}
+
+#[test]
+fn test_import_records_a_buy_at_the_pool_value() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    set_mex_price(&mut world, 2 * USDC);
+
+    import_mex(&mut world, nonce, mex(100), None);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(100));
+    assert_eq!(attributes.cost_basis, usdc(200));
+    assert_eq!(attributes.fee_cost_basis, usdc(200));
+    assert_eq!(attributes.scheduled_buys, 0);
+    assert_eq!(attributes.total_spent, 0u64);
+    world.check_account(USER).esdt_balance(MEX_ID, mex(9_900));
+}
+
+#[test]
+fn test_declared_import_cost_basis_does_not_lower_the_profit_fee() {
+    let mut world = setup_with_dex();
+    set_profit_fee_percentage(&mut world, 1_000);
+    let config = StrategyConfig {
+        take_profit_percentage: 1_000,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(1_000));
+
+    // 100 MEX worth 100 USDC, declared at 200 USDC
+    import_mex(&mut world, nonce, mex(100), Some(usdc(200)));
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.cost_basis, usdc(200));
+    assert_eq!(attributes.fee_cost_basis, usdc(100));
+
+    // The take profit target follows the declared cost basis, the 10% fee the pool value at import
+    set_mex_price(&mut world, 2 * USDC);
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .take_profit(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![nonce]),
+        )
+        .with_result(ExpectError(4, "No valid strategies to execute"))
+        .run();
+
+    set_mex_price(&mut world, 5 * USDC / 2);
+    bot_take_profit(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, 0u64);
+    assert_eq!(attributes.cost_basis, 0u64);
+    assert_eq!(attributes.fee_cost_basis, 0u64);
+    assert_eq!(attributes.quote_token_balance, usdc(1_235));
+}
+
+/// Creates the contract in the state of the single DCA token version, with a MEX strategy bought twice
+/// and the MEX setting and min amount per swap of that version.
+fn setup_legacy() -> ScenarioWorld {
+    let mut world = world();
+    setup_dex(&mut world);
+
+    let mut buys = ManagedVec::<StaticApi, Swap<StaticApi>>::new();
+    buys.push(Swap::new(
+        usdc(100),
+        mex(100),
+        START_TS_MILLIS - 2 * DAY_IN_MILLIS,
+    ));
+    buys.push(Swap::new(
+        usdc(100),
+        mex(100),
+        START_TS_MILLIS - DAY_IN_MILLIS,
+    ));
+    let legacy_attributes = LegacyStrategyTokenAttributes::<StaticApi> {
+        amount_per_swap: usdc(100),
+        dca_frequency: ManagedBuffer::from("Daily"),
+        frequency_in_millis: DAY_IN_MILLIS,
+        take_profit_percentage: 1_000,
+        usdc_balance: usdc(500),
+        dca_token_balance: mex(200),
+        last_executed_ts_millis: START_TS_MILLIS - DAY_IN_MILLIS,
+        buys,
+        sells: ManagedVec::new(),
+    };
+    let mut encoded_attributes = ManagedBuffer::<StaticApi>::new();
+    legacy_attributes
+        .top_encode(&mut encoded_attributes)
+        .unwrap();
+
+    world
+        .account(DCAI_SC)
+        .nonce(1)
+        .code(DCAI_PATH)
+        .owner(OWNER)
+        .esdt_roles(
+            STRATEGY_TOKEN_ID,
+            vec![
+                "ESDTRoleNFTCreate".to_string(),
+                "ESDTRoleNFTBurn".to_string(),
+                "ESDTRoleNFTUpdateAttributes".to_string(),
+            ],
+        )
+        .esdt_nft_balance(STRATEGY_TOKEN_ID, 1, 1, encoded_attributes)
+        .esdt_nft_last_nonce(STRATEGY_TOKEN_ID, 1)
+        .esdt_balance(USDC_ID, usdc(500))
+        .esdt_balance(MEX_ID, mex(200))
+        .storage_mandos("str:strategy_token", "str:DCAIMEX-123456")
+        .storage_mandos("str:dca_token", "str:MEX-455c57")
+        .storage_mandos("str:min_amount_per_swap", "10,000,000");
+    init_dcai(&mut world);
+
+    world
+}
+
+/// Upgrades the contract with the mock xExchange contracts.
+fn upgrade_dcai(world: &mut ScenarioWorld, legacy_dca_token_decimals: OptionalValue<u32>) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .upgrade(
+            ROUTER_SC,
+            WRAPPER_SC,
+            SAFE_PRICE_SC,
+            USDC_ID,
+            WEGLD_ID,
+            legacy_dca_token_decimals,
+        )
+        .code(DCAI_PATH)
+        .run();
+}
+
+#[test]
+fn test_upgrade_moves_the_single_dca_token_setup_to_the_allowlists() {
+    let mut world = setup_legacy();
+
+    // The decimals of the single DCA token are required
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .upgrade(
+            ROUTER_SC,
+            WRAPPER_SC,
+            SAFE_PRICE_SC,
+            USDC_ID,
+            WEGLD_ID,
+            OptionalValue::<u32>::None,
+        )
+        .code(DCAI_PATH)
+        .with_result(ExpectError(4, "Invalid DCA token"))
+        .run();
+
+    upgrade_dcai(&mut world, OptionalValue::Some(18));
+
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .dca_token_decimals(EgldOrEsdtTokenIdentifier::esdt(
+            MEX_ID.to_token_identifier(),
+        ))
+        .returns(ExpectValue(18u32))
+        .run();
+    world
+        .query()
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .allowed_quote_tokens()
+        .returns(ExpectValue(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            usdc(10),
+        ))])))
+        .run();
+    world
+        .check_account(DCAI_SC)
+        .check_storage("str:dca_token", "")
+        .check_storage("str:min_amount_per_swap", "");
+
+    // Once migrated, later upgrades need no decimals
+    upgrade_dcai(&mut world, OptionalValue::None);
+}
+
+#[test]
+fn test_upgrade_converts_legacy_strategies_when_read() {
+    let mut world = setup_legacy();
+    upgrade_dcai(&mut world, OptionalValue::Some(18));
+
+    let attributes = strategy_attributes(&mut world, 1);
+    assert_eq!(
+        attributes.dca_token,
+        EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier())
+    );
+    assert_eq!(
+        attributes.quote_token,
+        EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier())
+    );
+    assert_eq!(attributes.amount_per_swap, usdc(100));
+    assert_eq!(attributes.take_profit_percentage, 1_000);
+    assert_eq!(attributes.quote_token_balance, usdc(500));
+    assert_eq!(attributes.dca_token_balance, mex(200));
+    assert_eq!(attributes.cost_basis, usdc(200));
+    assert_eq!(attributes.scheduled_buys, 2);
+    assert_eq!(attributes.next_buy_ts_millis, START_TS_MILLIS);
+
+    // The next buy stores the strategy in the current format
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .add_allowed_frequencies(MultiValueVec::from(vec![MultiValue2::from((
+            ManagedBuffer::from("Daily"),
+            DAY_IN_MILLIS,
+        ))]))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_bot_address(BOT)
+        .run();
+    bot_buy(&mut world, vec![1]);
+
+    let attributes = strategy_attributes(&mut world, 1);
+    assert_eq!(attributes.dca_token_balance, mex(300));
+    assert_eq!(attributes.cost_basis, usdc(300));
+    assert_eq!(attributes.scheduled_buys, 3);
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + DAY_IN_MILLIS
+    );
+
+    let mut encoded_attributes = ManagedBuffer::<StaticApi>::new_from_bytes(&[1]);
+    let mut attributes_buffer = ManagedBuffer::<StaticApi>::new();
+    attributes.top_encode(&mut attributes_buffer).unwrap();
+    encoded_attributes.append(&attributes_buffer);
+    world
+        .check_account(DCAI_SC)
+        .esdt_nft_balance_and_attributes(STRATEGY_TOKEN_ID, 1, 1, encoded_attributes);
+}
+
+#[test]
+fn test_buy_batches_strategies_by_quote_token_and_splits_the_output_pro_rata() {
+    let mut world = setup_with_dex();
+    deploy_pair(&mut world, MEX_WEGLD_PAIR_SC, MEX_ID, WEGLD_ID);
+    // 1 MEX = 0.1 WEGLD
+    set_pair_reserves(&mut world, MEX_WEGLD_PAIR_SC, mex(10), mex(1));
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .add_allowed_quote_tokens(MultiValueVec::from(vec![MultiValue2::from((
+            EgldOrEsdtTokenIdentifier::esdt(WEGLD_ID.to_token_identifier()),
+            mex(1),
+        ))]))
+        .run();
+
+    let small_nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    let large_config = StrategyConfig {
+        amount_per_swap: usdc(300),
+        ..fixed_buy_config()
+    };
+    let large_nonce = create_mex_strategy(&mut world, large_config, usdc(1_000));
+    let wegld_config = StrategyConfig {
+        amount_per_swap: mex(10),
+        ..fixed_buy_config()
+    };
+    let wegld_nonce = create_strategy(&mut world, WEGLD_ID, wegld_config, mex(100));
+
+    bot_buy(&mut world, vec![small_nonce, wegld_nonce, large_nonce]);
+
+    // One swap per quote token, its output split by the share of the quote token each strategy spent
+    world
+        .check_account(MEX_USDC_PAIR_SC)
+        .esdt_balance(USDC_ID, usdc(100_400))
+        .esdt_balance(MEX_ID, mex(99_600));
+    world
+        .check_account(MEX_WEGLD_PAIR_SC)
+        .esdt_balance(WEGLD_ID, mex(100_010))
+        .esdt_balance(MEX_ID, mex(99_900));
+
+    let attributes = strategy_attributes(&mut world, small_nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(900));
+    assert_eq!(attributes.dca_token_balance, mex(100));
+    let attributes = strategy_attributes(&mut world, large_nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(700));
+    assert_eq!(attributes.dca_token_balance, mex(300));
+    let attributes = strategy_attributes(&mut world, wegld_nonce);
+    assert_eq!(attributes.quote_token_balance, mex(90));
+    assert_eq!(attributes.dca_token_balance, mex(100));
+    assert_eq!(attributes.cost_basis, mex(10));
+
+    world.check_account(DCAI_SC).esdt_balance(MEX_ID, mex(500));
+}
+
+#[test]
+fn test_dip_compares_the_pool_price_with_the_average_entry_price() {
+    let mut world = setup_with_dex();
+    set_pair_reserves(&mut world, MEX_USDC_PAIR_SC, mex(1_000), usdc(1_000));
+    let config = StrategyConfig {
+        dip_threshold_percentage: 1_000,
+        dip_multiplier_percentage: 20_000,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(1_000));
+    bot_buy(&mut world, vec![nonce]);
+    import_mex(&mut world, nonce, mex(5_000), None);
+
+    // At a flat price, selling the large position into the pool would fetch far less than its
+    // cost basis, but the pool price still matches the average entry price
+    world
+        .tx()
+        .from(OWNER)
+        .to(MEX_USDC_PAIR_SC)
+        .raw_call("setPriceImpact")
+        .argument(&true)
+        .run();
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(800));
+    assert_eq!(attributes.dca_token_balance, mex(5_200));
+
+    // 20% below the average entry price, the buy is doubled
+    set_mex_price(&mut world, 800_000);
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + 2 * DAY_IN_MILLIS);
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(600));
+    assert_eq!(attributes.dca_token_balance, mex(5_450));
+}
+
+#[test]
+fn test_buy_skips_strategies_above_their_max_buy_price() {
+    let mut world = setup_with_dex();
+    let config = StrategyConfig {
+        max_buy_price: usdc(1),
+        ..fixed_buy_config()
+    };
+    let capped_nonce = create_mex_strategy(&mut world, config, usdc(1_000));
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    assert!(strategy_price_condition_met(&mut world, capped_nonce));
+
+    // Above the ceiling, the capped strategy is skipped without failing the batch and stays due
+    set_mex_price(&mut world, 2 * USDC);
+    assert!(!strategy_price_condition_met(&mut world, capped_nonce));
+    bot_buy(&mut world, vec![capped_nonce, nonce]);
+
+    let attributes = strategy_attributes(&mut world, capped_nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(1_000));
+    assert_eq!(attributes.dca_token_balance, 0u64);
+    assert_eq!(attributes.last_executed_ts_millis, 0);
+    assert_eq!(attributes.next_buy_ts_millis, START_TS_MILLIS);
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).dca_token_balance,
+        mex(50)
+    );
+
+    // Back at the ceiling, it buys in the same interval
+    set_mex_price(&mut world, USDC);
+    bot_buy(&mut world, vec![capped_nonce]);
+
+    let attributes = strategy_attributes(&mut world, capped_nonce);
+    assert_eq!(attributes.dca_token_balance, mex(100));
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + DAY_IN_MILLIS
+    );
+}
+
+#[test]
+fn test_scheduled_sell_at_a_loss_charges_no_profit_fee() {
+    let mut world = setup_with_dex();
+    set_profit_fee_percentage(&mut world, 1_000);
+    let nonce = create_mex_strategy(&mut world, scheduled_sell_config(), usdc(10));
+
+    // 100 MEX imported at 2 USDC, then half of them sold at 1 USDC
+    set_mex_price(&mut world, 2 * USDC);
+    import_mex(&mut world, nonce, mex(100), None);
+    set_mex_price(&mut world, USDC);
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(50));
+    assert_eq!(attributes.cost_basis, usdc(100));
+    assert_eq!(attributes.quote_token_balance, usdc(60));
+    world
+        .check_account(DCAI_SC)
+        .esdt_balance(USDC_ID, usdc(60))
+        .esdt_balance(MEX_ID, mex(50));
+}
+
+#[test]
+fn test_scheduled_sells_are_batched_and_pay_the_profit_fee() {
+    let mut world = setup_with_dex();
+    set_profit_fee_percentage(&mut world, 1_000);
+    let nonce = create_mex_strategy(&mut world, scheduled_sell_config(), usdc(10));
+    let value_config = StrategyConfig {
+        direction: StrategyDirection::Sell,
+        amount_per_swap: usdc(150),
+        catch_up_policy: CatchUpPolicy::CatchUp,
+        max_catch_up_buys: 3,
+        ..fixed_buy_config()
+    };
+    let value_nonce = create_mex_strategy(&mut world, value_config, usdc(10));
+    import_mex(&mut world, nonce, mex(100), None);
+    import_mex(&mut world, value_nonce, mex(200), None);
+
+    // A day late at 2 USDC: 50 MEX, and twice the 75 MEX worth 150 USDC, sold in one swap
+    set_mex_price(&mut world, 2 * USDC);
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    bot_buy(&mut world, vec![nonce, value_nonce]);
+
+    world
+        .check_account(MEX_USDC_PAIR_SC)
+        .esdt_balance(USDC_ID, usdc(99_600))
+        .esdt_balance(MEX_ID, mex(100_200));
+
+    // Each pays 10% of its profit over the cost basis sold
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(50));
+    assert_eq!(attributes.cost_basis, usdc(50));
+    assert_eq!(attributes.quote_token_balance, usdc(105));
+    assert_eq!(attributes.scheduled_sells, 1);
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + 2 * DAY_IN_MILLIS
+    );
+
+    let attributes = strategy_attributes(&mut world, value_nonce);
+    assert_eq!(attributes.dca_token_balance, mex(50));
+    assert_eq!(attributes.cost_basis, usdc(50));
+    assert_eq!(attributes.quote_token_balance, usdc(295));
+    assert_eq!(attributes.scheduled_sells, 2);
+
+    world.check_account(BOT).esdt_balance(USDC_ID, usdc(20));
+    world
+        .check_account(DCAI_SC)
+        .esdt_balance(USDC_ID, usdc(400))
+        .esdt_balance(MEX_ID, mex(100));
+}
+
+#[test]
+fn test_deposit_swaps_another_token_to_the_quote_token() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    set_mex_price(&mut world, 2 * USDC);
+    let mex_token = EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier());
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit(OptionalValue::Some(usdc(201)))
+        .payment(deposit_payments(nonce, mex_token.clone(), mex(100)))
+        .with_result(ExpectError(4, "Swap output below minimum amount out"))
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit(OptionalValue::Some(usdc(200)))
+        .payment(deposit_payments(nonce, mex_token, mex(100)))
+        .run();
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(1_200));
+    assert_eq!(attributes.dca_token_balance, 0u64);
+    world
+        .check_account(MEX_USDC_PAIR_SC)
+        .esdt_balance(MEX_ID, mex(100_100))
+        .esdt_balance(USDC_ID, usdc(99_800));
+    world
+        .check_account(DCAI_SC)
+        .esdt_balance(USDC_ID, usdc(1_200))
+        .esdt_balance(MEX_ID, 0u64);
+    world.check_account(USER).esdt_balance(MEX_ID, mex(9_900));
+}
+
+#[test]
+fn test_deposit_wraps_egld_before_swapping() {
+    let mut world = setup_with_dex();
+    deploy_pair(&mut world, WEGLD_USDC_PAIR_SC, WEGLD_ID, USDC_ID);
+    // 1 WEGLD = 20 USDC
+    set_pair_reserves(&mut world, WEGLD_USDC_PAIR_SC, mex(1), usdc(20));
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit(OptionalValue::Some(usdc(20)))
+        .payment(deposit_payments(
+            nonce,
+            EgldOrEsdtTokenIdentifier::egld(),
+            mex(1),
+        ))
+        .run();
+
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).quote_token_balance,
+        usdc(1_020)
+    );
+    world
+        .check_account(WRAPPER_SC)
+        .balance(mex(1_001))
+        .esdt_balance(WEGLD_ID, mex(999_999));
+    world
+        .check_account(WEGLD_USDC_PAIR_SC)
+        .esdt_balance(WEGLD_ID, mex(100_001))
+        .esdt_balance(USDC_ID, usdc(99_980));
+    world
+        .check_account(DCAI_SC)
+        .balance(0u64)
+        .esdt_balance(WEGLD_ID, 0u64)
+        .esdt_balance(USDC_ID, usdc(1_020));
+}
+
+#[test]
+fn test_swapped_deposit_requires_a_min_amount_out() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    let mex_token = EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier());
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit(OptionalValue::<BigUint<StaticApi>>::None)
+        .payment(deposit_payments(nonce, mex_token.clone(), mex(100)))
+        .with_result(ExpectError(
+            4,
+            "Minimum amount out required for swapped deposits",
+        ))
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit(OptionalValue::Some(BigUint::<StaticApi>::zero()))
+        .payment(deposit_payments(nonce, mex_token, mex(100)))
+        .with_result(ExpectError(
+            4,
+            "Minimum amount out required for swapped deposits",
+        ))
+        .run();
+}
+
+#[test]
+fn test_deposit_of_the_quote_token_is_credited_directly() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit(OptionalValue::<BigUint<StaticApi>>::None)
+        .payment(deposit_payments(
+            nonce,
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            usdc(500),
+        ))
+        .run();
+
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).quote_token_balance,
+        usdc(1_500)
+    );
+    world
+        .check_account(MEX_USDC_PAIR_SC)
+        .esdt_balance(MEX_ID, mex(100_000))
+        .esdt_balance(USDC_ID, usdc(100_000));
+    world.check_account(USER).esdt_balance(USDC_ID, usdc(8_500));
+}
+
+#[test]
+fn test_buy_rejects_duplicate_and_unmatched_min_amounts_out() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    let usdc_to_mex = SwapMinAmountOut {
+        token_in: EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+        token_out: EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+        amount: mex(1),
+    };
+    let mex_to_usdc = SwapMinAmountOut {
+        token_in: EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+        token_out: EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+        amount: usdc(1),
+    };
+
+    // Two minimums for the same pair
+    let mut min_amounts_out = ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new();
+    min_amounts_out.push(usdc_to_mex.clone());
+    min_amounts_out.push(usdc_to_mex.clone());
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(0u64, min_amounts_out, MultiValueVec::from(vec![nonce]))
+        .with_result(ExpectError(4, "Invalid min amounts out"))
+        .run();
+
+    // A minimum for a pair the call does not swap
+    let mut min_amounts_out = ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new();
+    min_amounts_out.push(usdc_to_mex.clone());
+    min_amounts_out.push(mex_to_usdc);
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(0u64, min_amounts_out, MultiValueVec::from(vec![nonce]))
+        .with_result(ExpectError(4, "Invalid min amounts out"))
+        .run();
+
+    let mut min_amounts_out = ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new();
+    min_amounts_out.push(usdc_to_mex);
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(0u64, min_amounts_out, MultiValueVec::from(vec![nonce]))
+        .run();
+
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).dca_token_balance,
+        mex(100)
+    );
+}
+
+#[test]
+fn test_paused_strategy_is_skipped_and_resumes_on_its_schedule() {
+    let mut world = setup_with_dex();
+    let config = StrategyConfig {
+        catch_up_policy: CatchUpPolicy::CatchUp,
+        max_catch_up_buys: 3,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(1_000));
+    bot_buy(&mut world, vec![nonce]);
+    set_strategy_paused(&mut world, nonce, true);
+    assert!(strategy_attributes(&mut world, nonce).is_paused);
+
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![nonce]),
+        )
+        .with_result(ExpectError(4, "No valid strategies to execute"))
+        .run();
+
+    // Resumed halfway through the fourth day, only that day's buy is due
+    set_block_timestamp_millis(
+        &mut world,
+        START_TS_MILLIS + 4 * DAY_IN_MILLIS + DAY_IN_MILLIS / 2,
+    );
+    set_strategy_paused(&mut world, nonce, false);
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert!(!attributes.is_paused);
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + 4 * DAY_IN_MILLIS
+    );
+
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.scheduled_buys, 2);
+    assert_eq!(attributes.quote_token_balance, usdc(800));
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + 5 * DAY_IN_MILLIS
+    );
+}
+
+#[test]
+fn test_deposit_for_funds_someone_elses_strategy() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+
+    world
+        .tx()
+        .from(LIQUIDITY)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit_for(nonce)
+        .single_esdt(&USDC_ID.to_token_identifier(), 0, &usdc(500))
+        .run();
+
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).quote_token_balance,
+        usdc(1_500)
+    );
+    world
+        .check_account(LIQUIDITY)
+        .esdt_balance(USDC_ID, usdc(899_500));
+    world
+        .check_account(DCAI_SC)
+        .esdt_balance(USDC_ID, usdc(1_500));
+
+    // Only the strategy's quote token, and only for a live strategy
+    world
+        .tx()
+        .from(LIQUIDITY)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit_for(nonce)
+        .single_esdt(&MEX_ID.to_token_identifier(), 0, &mex(500))
+        .with_result(ExpectError(4, "Invalid quote token"))
+        .run();
+
+    world
+        .tx()
+        .from(LIQUIDITY)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit_for(nonce + 1)
+        .single_esdt(&USDC_ID.to_token_identifier(), 0, &usdc(500))
+        .with_result(ExpectError(4, "Insufficient strategy token balance"))
+        .run();
+}
+
+#[test]
+fn test_withdraw_all_sends_both_balances_and_keeps_the_strategy() {
+    let mut world = setup_with_dex();
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(1_000));
+    bot_buy(&mut world, vec![nonce]);
+
+    // Both balances go to the recipient
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .withdraw_all(OptionalValue::Some(OWNER.to_managed_address()))
+        .single_esdt(
+            &STRATEGY_TOKEN_ID.to_token_identifier(),
+            nonce,
+            &BigUint::from(1u64),
+        )
+        .run();
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.quote_token_balance, 0u64);
+    assert_eq!(attributes.dca_token_balance, 0u64);
+    assert_eq!(attributes.cost_basis, 0u64);
+    world
+        .check_account(OWNER)
+        .esdt_balance(USDC_ID, usdc(900))
+        .esdt_balance(MEX_ID, mex(100));
+    world.check_account(USER).esdt_balance(USDC_ID, usdc(9_000));
+    world
+        .check_account(DCAI_SC)
+        .esdt_balance(USDC_ID, 0u64)
+        .esdt_balance(MEX_ID, 0u64);
+
+    // The owner still holds the strategy token, with nothing left to withdraw
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .withdraw_all(OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .single_esdt(
+            &STRATEGY_TOKEN_ID.to_token_identifier(),
+            nonce,
+            &BigUint::from(1u64),
+        )
+        .with_result(ExpectError(4, "Nothing to withdraw"))
+        .run();
+
+    // Later deposits keep funding the same strategy
+    world
+        .tx()
+        .from(LIQUIDITY)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .deposit_for(nonce)
+        .single_esdt(&USDC_ID.to_token_identifier(), 0, &usdc(100))
+        .run();
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    bot_buy(&mut world, vec![nonce]);
+
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).dca_token_balance,
+        mex(100)
+    );
+}
+
+#[test]
+fn test_partial_take_profit_sells_its_share_and_waits_for_a_further_rise() {
+    let mut world = setup_with_dex();
+    let config = StrategyConfig {
+        take_profit_percentage: 1_000,
+        take_profit_sell_percentage: 5_000,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(1_000));
+    bot_buy(&mut world, vec![nonce]);
+
+    // 10% up, half of the 100 MEX is sold
+    set_mex_price(&mut world, 1_200_000);
+    bot_take_profit(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(50));
+    assert_eq!(attributes.cost_basis, usdc(50));
+    assert_eq!(attributes.quote_token_balance, usdc(960));
+    assert_eq!(attributes.sells.len(), 1);
+
+    // The rest is valued at the last sell price, so the same price does not take profit again
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .take_profit(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![nonce]),
+        )
+        .with_result(ExpectError(4, "No valid strategies to execute"))
+        .run();
+
+    // 10% above the last sell price, half of the rest is sold
+    set_mex_price(&mut world, 1_320_000);
+    bot_take_profit(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(25));
+    assert_eq!(attributes.cost_basis, usdc(25));
+    assert_eq!(attributes.quote_token_balance, usdc(993));
+}
+
+#[test]
+fn test_trailing_take_profit_sells_on_a_retrace_from_the_high() {
+    let mut world = setup_with_dex();
+    let config = StrategyConfig {
+        take_profit_percentage: 1_000,
+        trailing_take_profit_percentage: 500,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(1_000));
+    bot_buy(&mut world, vec![nonce]);
+
+    // Once in profit, the call only records the highest price
+    set_mex_price(&mut world, 1_200_000);
+    bot_take_profit(&mut world, vec![nonce]);
+    set_mex_price(&mut world, 1_500_000);
+    bot_take_profit(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(100));
+    assert_eq!(attributes.take_profit_high_water_mark, 1_500_000u64);
+
+    // 4% below the high is not enough of a retrace
+    set_mex_price(&mut world, 1_440_000);
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .take_profit(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![nonce]),
+        )
+        .with_result(ExpectError(4, "No valid strategies to execute"))
+        .run();
+
+    set_mex_price(&mut world, 1_425_000);
+    bot_take_profit(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, 0u64);
+    assert_eq!(attributes.quote_token_balance, usdc(900) + 142_500_000u64);
+    assert_eq!(attributes.take_profit_high_water_mark, 0u64);
+}
+
+#[test]
+fn test_take_profit_proceeds_follow_the_post_take_profit_action() {
+    let mut world = setup_with_dex();
+    let withdraw_config = StrategyConfig {
+        take_profit_percentage: 1_000,
+        post_take_profit_action: PostTakeProfitAction::Withdraw,
+        ..fixed_buy_config()
+    };
+    let withdraw_nonce = create_mex_strategy(&mut world, withdraw_config, usdc(1_000));
+    let pause_config = StrategyConfig {
+        take_profit_percentage: 1_000,
+        post_take_profit_action: PostTakeProfitAction::Pause,
+        ..fixed_buy_config()
+    };
+    let pause_nonce = create_mex_strategy(&mut world, pause_config, usdc(1_000));
+    bot_buy(&mut world, vec![withdraw_nonce, pause_nonce]);
+
+    set_mex_price(&mut world, 2 * USDC);
+    bot_take_profit(&mut world, vec![withdraw_nonce, pause_nonce]);
+
+    // The withdrawn proceeds go to the owner, the paused strategy keeps them
+    let attributes = strategy_attributes(&mut world, withdraw_nonce);
+    assert_eq!(attributes.dca_token_balance, 0u64);
+    assert_eq!(attributes.quote_token_balance, usdc(900));
+    assert!(!attributes.is_paused);
+    world.check_account(USER).esdt_balance(USDC_ID, usdc(8_200));
+
+    let attributes = strategy_attributes(&mut world, pause_nonce);
+    assert_eq!(attributes.dca_token_balance, 0u64);
+    assert_eq!(attributes.quote_token_balance, usdc(1_100));
+    assert!(attributes.is_paused);
+
+    // Only the strategy that was not paused buys again
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    bot_buy(&mut world, vec![withdraw_nonce, pause_nonce]);
+
+    assert_eq!(
+        strategy_attributes(&mut world, withdraw_nonce).dca_token_balance,
+        mex(50)
+    );
+    assert_eq!(
+        strategy_attributes(&mut world, pause_nonce).dca_token_balance,
+        0u64
+    );
+}
+
+#[test]
+fn test_strategies_complete_on_their_end_conditions() {
+    let mut world = setup_with_dex();
+    let max_buys_config = StrategyConfig {
+        max_buys: 2,
+        ..fixed_buy_config()
+    };
+    let max_buys_nonce = create_mex_strategy(&mut world, max_buys_config, usdc(1_000));
+    let end_config = StrategyConfig {
+        amount_per_swap: usdc(200),
+        end_ts_millis: START_TS_MILLIS + DAY_IN_MILLIS + DAY_IN_MILLIS / 2,
+        ..fixed_buy_config()
+    };
+    let end_nonce = create_mex_strategy(&mut world, end_config, usdc(1_000));
+    let budget_config = StrategyConfig {
+        max_total_spend: usdc(250),
+        ..fixed_buy_config()
+    };
+    let budget_nonce = create_mex_strategy(&mut world, budget_config, usdc(1_000));
+    let nonces = vec![max_buys_nonce, end_nonce, budget_nonce];
+
+    bot_buy(&mut world, nonces.clone());
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + DAY_IN_MILLIS);
+    bot_buy(&mut world, nonces.clone());
+
+    // Completed right after the buy reaching the max buys, or leaving too little budget for another
+    assert!(strategy_attributes(&mut world, max_buys_nonce).is_completed);
+    assert!(strategy_attributes(&mut world, budget_nonce).is_completed);
+    assert!(!strategy_attributes(&mut world, end_nonce).is_completed);
+
+    // Past its end timestamp, the call only completes the last one
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + 2 * DAY_IN_MILLIS);
+    bot_buy(&mut world, nonces.clone());
+
+    for nonce in nonces.iter() {
+        let attributes = strategy_attributes(&mut world, *nonce);
+        assert!(attributes.is_completed);
+        assert_eq!(attributes.scheduled_buys, 2);
+    }
+    let attributes = strategy_attributes(&mut world, end_nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(600));
+    assert_eq!(attributes.dca_token_balance, mex(400));
+    let attributes = strategy_attributes(&mut world, budget_nonce);
+    assert_eq!(attributes.total_spent, usdc(200));
+    assert_eq!(attributes.dca_token_balance, mex(200));
+
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(nonces),
+        )
+        .with_result(ExpectError(4, "No valid strategies to execute"))
+        .run();
+}
+
+#[test]
+fn test_delayed_start_schedules_buys_from_the_start_timestamp() {
+    let mut world = setup_with_dex();
+    let start_ts_millis = START_TS_MILLIS + 2 * DAY_IN_MILLIS + DAY_IN_MILLIS / 4;
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .create_strategy(
+            EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            StrategyConfig {
+                start_ts_millis: START_TS_MILLIS,
+                ..fixed_buy_config()
+            },
+        )
+        .single_esdt(&USDC_ID.to_token_identifier(), 0, &usdc(1_000))
+        .with_result(ExpectError(4, "Invalid start timestamp"))
+        .run();
+
+    let config = StrategyConfig {
+        start_ts_millis,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config.clone(), usdc(1_000));
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).next_buy_ts_millis,
+        start_ts_millis
+    );
+
+    set_block_timestamp_millis(&mut world, start_ts_millis - 1);
+    world
+        .tx()
+        .from(BOT)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .buy(
+            0u64,
+            ManagedVec::<StaticApi, SwapMinAmountOut<StaticApi>>::new(),
+            MultiValueVec::from(vec![nonce]),
+        )
+        .with_result(ExpectError(4, "No valid strategies to execute"))
+        .run();
+
+    set_block_timestamp_millis(&mut world, start_ts_millis);
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.dca_token_balance, mex(100));
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        start_ts_millis + DAY_IN_MILLIS
+    );
+
+    // The start is locked once the schedule has bought
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .modify_strategy(StrategyConfig {
+            start_ts_millis: start_ts_millis + DAY_IN_MILLIS,
+            ..config
+        })
+        .single_esdt(
+            &STRATEGY_TOKEN_ID.to_token_identifier(),
+            nonce,
+            &BigUint::from(1u64),
+        )
+        .with_result(ExpectError(
+            4,
+            "Start timestamp cannot change after the first buy",
+        ))
+        .run();
+}
+
+#[test]
+fn test_custom_frequency_within_the_bounds_schedules_buys() {
+    let mut world = setup_with_dex();
+    let hour_in_millis = 3_600_000u64;
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_frequency_duration_bounds(hour_in_millis, 7 * DAY_IN_MILLIS)
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .create_strategy(
+            EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            StrategyConfig {
+                frequency_in_millis: hour_in_millis / 2,
+                ..fixed_buy_config()
+            },
+        )
+        .single_esdt(&USDC_ID.to_token_identifier(), 0, &usdc(1_000))
+        .with_result(ExpectError(4, "Invalid DCA frequency"))
+        .run();
+
+    let config = StrategyConfig {
+        frequency_in_millis: 6 * hour_in_millis,
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(1_000));
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert!(attributes.dca_frequency.is_empty());
+    assert_eq!(
+        attributes.next_buy_ts_millis,
+        START_TS_MILLIS + 6 * hour_in_millis
+    );
+
+    set_block_timestamp_millis(&mut world, START_TS_MILLIS + 6 * hour_in_millis);
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.scheduled_buys, 2);
+    assert_eq!(attributes.dca_token_balance, mex(200));
+}
+
+#[test]
+fn test_create_strategy_is_funded_with_its_initial_deposit() {
+    let mut world = setup_with_dex();
+    world
+        .tx()
+        .from(OWNER)
+        .to(DCAI_SC)
+        .typed(dcai_proxy::DcaiProxy)
+        .set_min_initial_deposit(
+            EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+            usdc(500),
+        )
+        .run();
+
+    for (token, amount, error) in [
+        (USDC_ID, usdc(499), "Initial deposit below minimum"),
+        (MEX_ID, mex(500), "Invalid quote token"),
+    ] {
+        world
+            .tx()
+            .from(USER)
+            .to(DCAI_SC)
+            .typed(dcai_proxy::DcaiProxy)
+            .create_strategy(
+                EgldOrEsdtTokenIdentifier::esdt(MEX_ID.to_token_identifier()),
+                EgldOrEsdtTokenIdentifier::esdt(USDC_ID.to_token_identifier()),
+                fixed_buy_config(),
+            )
+            .single_esdt(&token.to_token_identifier(), 0, &amount)
+            .with_result(ExpectError(4, error))
+            .run();
+    }
+
+    // Funded at creation, the strategy buys on the first call
+    let nonce = create_mex_strategy(&mut world, fixed_buy_config(), usdc(500));
+    assert_eq!(
+        strategy_attributes(&mut world, nonce).quote_token_balance,
+        usdc(500)
+    );
+    world
+        .check_account(DCAI_SC)
+        .esdt_balance(USDC_ID, usdc(500));
+    world.check_account(USER).esdt_balance(USDC_ID, usdc(9_500));
+
+    bot_buy(&mut world, vec![nonce]);
+
+    let attributes = strategy_attributes(&mut world, nonce);
+    assert_eq!(attributes.quote_token_balance, usdc(400));
+    assert_eq!(attributes.dca_token_balance, mex(100));
+}
+
+#[test]
+fn test_value_averaging_buys_up_to_the_target_value_within_the_limits() {
+    let mut world = setup_with_dex();
+    let config = StrategyConfig {
+        buy_sizing_mode: BuySizingMode::ValueAveraging,
+        min_amount_per_swap: usdc(50),
+        max_amount_per_swap: usdc(300),
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(2_000));
+
+    // Targets of 100, 200, 300 and 400 USDC of position value after each buy
+    let expected = [
+        (USDC, usdc(100), mex(100)),
+        // 100 MEX worth 50 USDC, 150 USDC buys 300 MEX
+        (USDC / 2, usdc(150), mex(400)),
+        // 400 MEX worth 800 USDC, above the target, the min amount buys 25 MEX
+        (2 * USDC, usdc(50), mex(425)),
+        // 425 MEX worth 42.5 USDC, the max amount buys 3000 MEX
+        (USDC / 10, usdc(300), mex(3_425)),
+    ];
+    let mut quote_token_balance = usdc(2_000);
+    for (day, (price, spent, dca_token_balance)) in expected.into_iter().enumerate() {
+        set_mex_price(&mut world, price);
+        set_block_timestamp_millis(&mut world, START_TS_MILLIS + day as u64 * DAY_IN_MILLIS);
+        bot_buy(&mut world, vec![nonce]);
+
+        quote_token_balance -= spent;
+        let attributes = strategy_attributes(&mut world, nonce);
+        assert_eq!(attributes.quote_token_balance, quote_token_balance);
+        assert_eq!(attributes.dca_token_balance, dca_token_balance);
+    }
+}
+
+#[test]
+fn test_percentage_of_balance_buys_a_share_of_the_balance_at_least_the_min_amount() {
+    let mut world = setup_with_dex();
+    let config = StrategyConfig {
+        buy_sizing_mode: BuySizingMode::PercentageOfBalance,
+        balance_percentage_per_swap: 1_000,
+        min_amount_per_swap: usdc(50),
+        ..fixed_buy_config()
+    };
+    let nonce = create_mex_strategy(&mut world, config, usdc(600));
+
+    // 10% of 600 USDC, then of 540 USDC, then the 50 USDC min amount over 10% of 486 USDC
+    let expected = [
+        (usdc(540), mex(60)),
+        (usdc(486), mex(114)),
+        (usdc(436), mex(164)),
+    ];
+    for (day, (quote_token_balance, dca_token_balance)) in expected.into_iter().enumerate() {
+        set_block_timestamp_millis(&mut world, START_TS_MILLIS + day as u64 * DAY_IN_MILLIS);
+        bot_buy(&mut world, vec![nonce]);
+
+        let attributes = strategy_attributes(&mut world, nonce);
+        assert_eq!(attributes.quote_token_balance, quote_token_balance);
+        assert_eq!(attributes.dca_token_balance, dca_token_balance);
+    }
+}
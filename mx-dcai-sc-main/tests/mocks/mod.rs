@@ -0,0 +1,309 @@
+//! Mock xExchange contracts for the DCAi scenario tests
+//!
+//! The pair mock quotes and swaps at the fixed price of its configured reserves, so tests
+//! can move the price between operations. The swaps pay out of the tokens added as
+//! liquidity and do not move the reserves. Its `getAmountOut` can be set to quote with the
+//! price impact of a constant product pool, to tell pool values from pool prices.
+
+pub mod pair_mock {
+    use multiversx_sc::imports::*;
+
+    #[multiversx_sc::contract]
+    pub trait PairMock {
+        #[init]
+        fn init(
+            &self,
+            first_token_id: TokenIdentifier,
+            second_token_id: TokenIdentifier,
+            first_reserve: BigUint,
+            second_reserve: BigUint,
+        ) {
+            self.first_token_id().set(first_token_id);
+            self.second_token_id().set(second_token_id);
+            self.set_reserves(first_reserve, second_reserve);
+        }
+
+        #[endpoint(setReserves)]
+        fn set_reserves(&self, first_reserve: BigUint, second_reserve: BigUint) {
+            self.first_reserve().set(first_reserve);
+            self.second_reserve().set(second_reserve);
+        }
+
+        #[endpoint(setPriceImpact)]
+        fn set_price_impact(&self, price_impact: bool) {
+            self.price_impact().set(price_impact);
+        }
+
+        /// Accepts the tokens the swaps pay out.
+        #[payable]
+        #[endpoint(addLiquidity)]
+        fn add_liquidity(&self) {}
+
+        #[view(getAmountOut)]
+        fn get_amount_out_view(&self, token_in: TokenIdentifier, amount_in: BigUint) -> BigUint {
+            if !self.price_impact().get() {
+                return self.get_equivalent(token_in, amount_in);
+            }
+
+            let (reserve_in, reserve_out) = if token_in == self.first_token_id().get() {
+                (self.first_reserve().get(), self.second_reserve().get())
+            } else {
+                require!(token_in == self.second_token_id().get(), "Unknown token");
+                (self.second_reserve().get(), self.first_reserve().get())
+            };
+            &amount_in * &reserve_out / (reserve_in + &amount_in)
+        }
+
+        #[view(getEquivalent)]
+        fn get_equivalent(&self, token_in: TokenIdentifier, amount_in: BigUint) -> BigUint {
+            if token_in == self.first_token_id().get() {
+                amount_in * self.second_reserve().get() / self.first_reserve().get()
+            } else {
+                require!(token_in == self.second_token_id().get(), "Unknown token");
+                amount_in * self.first_reserve().get() / self.second_reserve().get()
+            }
+        }
+
+        #[view(getReserve)]
+        fn get_reserve(&self, token_id: TokenIdentifier) -> BigUint {
+            if token_id == self.first_token_id().get() {
+                self.first_reserve().get()
+            } else if token_id == self.second_token_id().get() {
+                self.second_reserve().get()
+            } else {
+                BigUint::zero()
+            }
+        }
+
+        #[payable]
+        #[endpoint(swapTokensFixedInput)]
+        fn swap_tokens_fixed_input(
+            &self,
+            token_out: TokenIdentifier,
+            amount_out_min: BigUint,
+        ) -> EsdtTokenPayment {
+            let payment = self.call_value().single_esdt().clone();
+            let amount_out = self.get_equivalent(payment.token_identifier, payment.amount);
+            require!(amount_out >= amount_out_min, "Slippage exceeded");
+
+            self.tx()
+                .to(&self.blockchain().get_caller())
+                .single_esdt(&token_out, 0, &amount_out)
+                .transfer();
+
+            EsdtTokenPayment::new(token_out, 0, amount_out)
+        }
+
+        #[view(getFirstTokenId)]
+        #[storage_mapper("first_token_id")]
+        fn first_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+        #[view(getSecondTokenId)]
+        #[storage_mapper("second_token_id")]
+        fn second_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+        #[storage_mapper("first_reserve")]
+        fn first_reserve(&self) -> SingleValueMapper<BigUint>;
+
+        #[storage_mapper("second_reserve")]
+        fn second_reserve(&self) -> SingleValueMapper<BigUint>;
+
+        #[storage_mapper("price_impact")]
+        fn price_impact(&self) -> SingleValueMapper<bool>;
+    }
+}
+
+pub mod router_mock {
+    use multiversx_sc::imports::*;
+
+    pub type SwapOperationType<M> =
+        MultiValue4<ManagedAddress<M>, ManagedBuffer<M>, TokenIdentifier<M>, BigUint<M>>;
+
+    #[multiversx_sc::contract]
+    pub trait RouterMock {
+        #[init]
+        fn init(&self) {}
+
+        #[endpoint(setPair)]
+        fn set_pair(
+            &self,
+            first_token_id: TokenIdentifier,
+            second_token_id: TokenIdentifier,
+            address: ManagedAddress,
+        ) {
+            self.pair(&first_token_id, &second_token_id).set(&address);
+            self.pair(&second_token_id, &first_token_id).set(&address);
+        }
+
+        #[view(getPair)]
+        fn get_pair(
+            &self,
+            first_token_id: TokenIdentifier,
+            second_token_id: TokenIdentifier,
+        ) -> ManagedAddress {
+            let pair = self.pair(&first_token_id, &second_token_id);
+            if pair.is_empty() {
+                ManagedAddress::zero()
+            } else {
+                pair.get()
+            }
+        }
+
+        #[payable]
+        #[endpoint(multiPairSwap)]
+        fn multi_pair_swap(
+            &self,
+            swap_operations: MultiValueEncoded<SwapOperationType<Self::Api>>,
+        ) -> ManagedVec<EsdtTokenPayment> {
+            let mut payment = self.call_value().single_esdt().clone();
+            let mut payments = ManagedVec::new();
+            for swap_operation in swap_operations.into_iter() {
+                let (pair_address, function, token_out, amount_out_min) =
+                    swap_operation.into_tuple();
+                payment = self
+                    .tx()
+                    .to(&pair_address)
+                    .raw_call(function)
+                    .argument(&token_out)
+                    .argument(&amount_out_min)
+                    .payment(payment)
+                    .original_result::<EsdtTokenPayment>()
+                    .returns(ReturnsResult)
+                    .sync_call();
+                payments.push(payment.clone());
+            }
+
+            self.tx()
+                .to(&self.blockchain().get_caller())
+                .payment(payment)
+                .transfer();
+
+            payments
+        }
+
+        #[storage_mapper("pair")]
+        fn pair(
+            &self,
+            first_token_id: &TokenIdentifier,
+            second_token_id: &TokenIdentifier,
+        ) -> SingleValueMapper<ManagedAddress>;
+    }
+}
+
+pub mod safe_price_mock {
+    use multiversx_sc::imports::*;
+
+    const MAX_PERCENTAGE: u64 = 10_000;
+
+    /// Quotes the safe price at the pair's current price, scaled by a settable percentage so
+    /// tests can move the safe price away from the pool price.
+    #[multiversx_sc::contract]
+    pub trait SafePriceMock {
+        #[init]
+        fn init(&self) {
+            self.price_percentage().set(MAX_PERCENTAGE);
+        }
+
+        #[endpoint(setPricePercentage)]
+        fn set_price_percentage(&self, price_percentage: u64) {
+            self.price_percentage().set(price_percentage);
+        }
+
+        #[view(getSafePriceByDefaultOffset)]
+        fn get_safe_price_by_default_offset(
+            &self,
+            pair_address: ManagedAddress,
+            input_payment: EsdtTokenPayment,
+        ) -> EsdtTokenPayment {
+            let first_token_id: TokenIdentifier = self
+                .tx()
+                .to(&pair_address)
+                .raw_call("getFirstTokenId")
+                .original_result::<TokenIdentifier>()
+                .returns(ReturnsResult)
+                .sync_call_readonly();
+            let second_token_id: TokenIdentifier = self
+                .tx()
+                .to(&pair_address)
+                .raw_call("getSecondTokenId")
+                .original_result::<TokenIdentifier>()
+                .returns(ReturnsResult)
+                .sync_call_readonly();
+            let amount_out: BigUint = self
+                .tx()
+                .to(&pair_address)
+                .raw_call("getEquivalent")
+                .argument(&input_payment.token_identifier)
+                .argument(&input_payment.amount)
+                .original_result::<BigUint>()
+                .returns(ReturnsResult)
+                .sync_call_readonly();
+
+            let token_out = if input_payment.token_identifier == first_token_id {
+                second_token_id
+            } else {
+                first_token_id
+            };
+            EsdtTokenPayment::new(
+                token_out,
+                0,
+                amount_out * self.price_percentage().get() / MAX_PERCENTAGE,
+            )
+        }
+
+        #[storage_mapper("price_percentage")]
+        fn price_percentage(&self) -> SingleValueMapper<u64>;
+    }
+}
+
+pub mod wrapper_mock {
+    use multiversx_sc::imports::*;
+
+    /// Wraps and unwraps EGLD one to one, out of the WEGLD and EGLD it holds.
+    #[multiversx_sc::contract]
+    pub trait WrapperMock {
+        #[init]
+        fn init(&self, wrapped_egld_token_id: TokenIdentifier) {
+            self.wrapped_egld_token_id().set(wrapped_egld_token_id);
+        }
+
+        /// Accepts the EGLD and WEGLD the wrapping pays out.
+        #[payable]
+        #[endpoint(addLiquidity)]
+        fn add_liquidity(&self) {}
+
+        #[payable("EGLD")]
+        #[endpoint(wrapEgld)]
+        fn wrap_egld(&self) -> EsdtTokenPayment {
+            let payment_amount = self.call_value().egld().clone();
+            let payment =
+                EsdtTokenPayment::new(self.wrapped_egld_token_id().get(), 0, payment_amount);
+
+            self.tx()
+                .to(&self.blockchain().get_caller())
+                .payment(&payment)
+                .transfer();
+
+            payment
+        }
+
+        #[payable]
+        #[endpoint(unwrapEgld)]
+        fn unwrap_egld(&self) {
+            let payment = self.call_value().single_esdt().clone();
+            require!(
+                payment.token_identifier == self.wrapped_egld_token_id().get(),
+                "Wrong token"
+            );
+
+            self.tx()
+                .to(&self.blockchain().get_caller())
+                .egld(&payment.amount)
+                .transfer();
+        }
+
+        #[view(getWrappedEgldTokenId)]
+        #[storage_mapper("wrapped_egld_token_id")]
+        fn wrapped_egld_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+    }
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           32
+// Endpoints:                           56
 // Async Callback:                       1
-// Total number of exported functions:  35
+// Total number of exported functions:  59
 
 #![no_std]
 
@@ -27,28 +27,52 @@ multiversx_sc_wasm_adapter::endpoints! {
         pause => pause
         unpause => unpause
         isPaused => is_paused
+        setUsdcIdentifier => set_usdc_identifier
+        setWegldIdentifier => set_wegld_identifier
         getUsdcIdentifier => get_usdc_identifier
         getWegldIdentifier => get_wegld_identifier
+        setSafePriceViewScAddress => set_safe_price_view_sc_address
+        setMaxPriceDeviationPercentage => set_max_price_deviation_percentage
+        getSafePriceViewScAddress => get_safe_price_view_sc_address
+        getMaxPriceDeviationPercentage => get_final_max_price_deviation_percentage
+        setXexchangeRouterScAddress => set_xexchange_router_sc_address
         getXexchangeRouterScAddress => get_xexchange_router_sc_address
+        setWrapperScAddress => set_wrapper_sc_address
         getWrapperScAddress => get_wrapper_sc_address
         setBotAddress => set_bot_address
         buy => buy
         takeProfit => take_profit
+        stopLoss => stop_loss
         isBot => is_bot
         getBotAddress => bot_address
         setup => setup
-        setToken => set_dca_token
-        setMinAmountPerSwap => set_min_amount_per_swap
+        addAllowedDcaTokens => add_allowed_dca_tokens
+        removeAllowedDcaTokens => remove_allowed_dca_tokens
+        addAllowedQuoteTokens => add_allowed_quote_tokens
+        removeAllowedQuoteTokens => remove_allowed_quote_tokens
         addAllowedFrequencies => add_allowed_frequencies
         removeAllowedFrequencies => remove_allowed_frequencies
         setProfitFeePercentage => set_profit_fee_percentage
         setCustomSlippagePercentage => set_custom_slippage_percentage
+        setMaxSlippagePercentageCap => set_max_slippage_percentage_cap
+        getMaxSlippagePercentageCap => get_final_max_slippage_percentage_cap
+        setMinInitialDeposit => set_min_initial_deposit
+        setFrequencyDurationBounds => set_frequency_duration_bounds
+        getFrequencyDurationBounds => get_frequency_duration_bounds
         getSetup => get_setup
+        getDcaTokenDecimals => get_dca_token_decimals
+        getAllowedQuoteTokens => allowed_quote_tokens
+        getMinInitialDeposit => min_initial_deposit
         createStrategy => create_strategy
         modifyStrategy => modify_strategy
         deleteStrategy => delete_strategy
+        pauseStrategy => pause_strategy
+        resumeStrategy => resume_strategy
         deposit => deposit
+        depositFor => deposit_for
+        importDcaToken => import_dca_token
         withdraw => withdraw
+        withdrawAll => withdraw_all
         getStrategyTokenAttributes => get_strategy_token_attributes_view
         getAllStrategies => get_all_strategies
         getAllStrategiesOnlyNonces => get_all_strategies_only_nonces